@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{number::Number, r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(Box::new(Type::Char), Box::new(Type::Number));
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        Ok(Value::Number(Number::from(value.unwrap_char() as i64)))
+    }));
+
+    Module::new("char_code", r#type, value)
+}
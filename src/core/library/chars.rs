@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{list::List, r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(Box::new(Type::String), Box::new(Type::list(Type::Char)));
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        Ok(Value::List(List::from(
+            value
+                .unwrap_string()
+                .chars()
+                .map(Value::Char)
+                .collect::<Vec<Value>>(),
+        )))
+    }));
+
+    Module::new("chars", r#type, value)
+}
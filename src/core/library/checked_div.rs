@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    error::Error,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Number),
+        Box::new(Type::Closure(
+            Box::new(Type::Number),
+            Box::new(Type::result(Type::String, Type::Number)),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| {
+                let value1 = value1.clone().unwrap_number();
+                let value2 = value2.unwrap_number();
+                Ok(match value1.checked_div(value2) {
+                    Ok(number) => Value::ok(Value::Number(number)),
+                    Err(Error::RuntimeError(_, message)) => Value::err(Value::String(message)),
+                    Err(error) => return Err(error),
+                })
+            },
+        )))
+    }));
+
+    Module::new("checked_div", r#type, value)
+}
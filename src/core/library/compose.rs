@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Closure(
+            Box::new(Type::Generic(0)),
+            Box::new(Type::Generic(1)),
+        )),
+        Box::new(Type::Closure(
+            Box::new(Type::Closure(
+                Box::new(Type::Generic(1)),
+                Box::new(Type::Generic(2)),
+            )),
+            Box::new(Type::Closure(
+                Box::new(Type::Generic(0)),
+                Box::new(Type::Generic(2)),
+            )),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| {
+                let value1 = value1.clone();
+                Ok(Value::Closure(Arc::new(
+                    move |value3: Value, interpreter: &mut Interpreter| {
+                        let value = value1.clone().apply(value3, interpreter)?;
+                        value2.clone().apply(value, interpreter)
+                    },
+                )))
+            },
+        )))
+    }));
+
+    Module::new("compose", r#type, value)
+}
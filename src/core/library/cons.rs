@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{list::List, r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Generic(0)),
+        Box::new(Type::Closure(
+            Box::new(Type::list(Type::Generic(0))),
+            Box::new(Type::list(Type::Generic(0))),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| {
+                Ok(Value::List(List::cons(
+                    value1.clone(),
+                    value2.unwrap_list(),
+                )))
+            },
+        )))
+    }));
+
+    Module::new("cons", r#type, value)
+}
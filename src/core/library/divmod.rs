@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Number),
+        Box::new(Type::Closure(
+            Box::new(Type::Number),
+            Box::new(Type::Tuple(vec![Type::Number, Type::Number])),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| {
+                let value1 = value1.clone().unwrap_number();
+                let value2 = value2.unwrap_number();
+                Ok(Value::Tuple(vec![
+                    Value::Number(value1.clone().div_euclid(value2.clone())?),
+                    Value::Number(value1.checked_rem(value2)?),
+                ]))
+            },
+        )))
+    }));
+
+    Module::new("divmod", r#type, value)
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{list::List, r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Closure(
+            Box::new(Type::Generic(0)),
+            Box::new(Type::Boolean),
+        )),
+        Box::new(Type::Closure(
+            Box::new(Type::list(Type::Generic(0))),
+            Box::new(Type::list(Type::Generic(0))),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, interpreter: &mut Interpreter| {
+                let mut values = vec![];
+                for value in value2.unwrap_list().iter() {
+                    let keep = value1.clone().apply(value.clone(), interpreter)?;
+                    if keep.unwrap_bool() {
+                        values.push(value.clone());
+                    }
+                }
+                Ok(Value::List(List::from(values)))
+            },
+        )))
+    }));
+
+    Module::new("filter", r#type, value)
+}
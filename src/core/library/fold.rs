@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Closure(
+            Box::new(Type::Generic(1)),
+            Box::new(Type::Closure(
+                Box::new(Type::Generic(0)),
+                Box::new(Type::Generic(1)),
+            )),
+        )),
+        Box::new(Type::Closure(
+            Box::new(Type::Generic(1)),
+            Box::new(Type::Closure(
+                Box::new(Type::list(Type::Generic(0))),
+                Box::new(Type::Generic(1)),
+            )),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| {
+                let value1 = value1.clone();
+                Ok(Value::Closure(Arc::new(
+                    move |value3: Value, interpreter: &mut Interpreter| {
+                        let mut accumulator = value2.clone();
+                        for value in value3.unwrap_list().iter() {
+                            let step = value1.clone().apply(accumulator, interpreter)?;
+                            accumulator = step.apply(value.clone(), interpreter)?;
+                        }
+                        Ok(accumulator)
+                    },
+                )))
+            },
+        )))
+    }));
+
+    Module::new("fold", r#type, value)
+}
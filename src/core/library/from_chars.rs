@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(Box::new(Type::list(Type::Char)), Box::new(Type::String));
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        Ok(Value::String(
+            value
+                .unwrap_list()
+                .iter()
+                .map(|char| char.clone().unwrap_char())
+                .collect(),
+        ))
+    }));
+
+    Module::new("from_chars", r#type, value)
+}
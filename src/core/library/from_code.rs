@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(Box::new(Type::Number), Box::new(Type::option(Type::Char)));
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        let char = value
+            .unwrap_number()
+            .as_int()
+            .and_then(|code| u32::try_from(code).ok())
+            .and_then(char::from_u32);
+
+        Ok(match char {
+            Some(char) => Value::some(Value::Char(char)),
+            None => Value::none(),
+        })
+    }));
+
+    Module::new("from_code", r#type, value)
+}
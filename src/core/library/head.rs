@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    error::Error,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::list(Type::Generic(0))),
+        Box::new(Type::Generic(0)),
+    );
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        match value.unwrap_list().head() {
+            Some(head) => Ok(head.clone()),
+            None => Err(Error::RuntimeError(
+                0..0,
+                String::from("Cannot take the head of an empty list"),
+            )),
+        }
+    }));
+
+    Module::new("head", r#type, value)
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{number::Number, r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::list(Type::Generic(0))),
+        Box::new(Type::Number),
+    );
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        Ok(Value::Number(
+            Number::from(value.unwrap_list().len() as i64),
+        ))
+    }));
+
+    Module::new("length", r#type, value)
+}
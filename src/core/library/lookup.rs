@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Generic(0)),
+        Box::new(Type::Closure(
+            Box::new(Type::list(Type::Tuple(vec![
+                Type::Generic(0),
+                Type::Generic(1),
+            ]))),
+            Box::new(Type::option(Type::Generic(1))),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| {
+                for entry in value2.unwrap_list().iter() {
+                    let mut entry = entry.clone().unwrap_tuple();
+                    if entry[0] == value1 {
+                        return Ok(Value::some(entry.remove(1)));
+                    }
+                }
+
+                Ok(Value::none())
+            },
+        )))
+    }));
+
+    Module::new("lookup", r#type, value)
+}
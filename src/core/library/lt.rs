@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Generic(0)),
+        Box::new(Type::Closure(
+            Box::new(Type::Generic(0)),
+            Box::new(Type::Boolean),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        let value1 = value1.comparable()?;
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| Ok(Value::Boolean(value1 < value2)),
+        )))
+    }));
+
+    Module::new("lt", r#type, value).comparing(vec![0])
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{list::List, r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Closure(
+            Box::new(Type::Generic(0)),
+            Box::new(Type::Generic(1)),
+        )),
+        Box::new(Type::Closure(
+            Box::new(Type::list(Type::Generic(0))),
+            Box::new(Type::list(Type::Generic(1))),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, interpreter: &mut Interpreter| {
+                let mut values = vec![];
+                for value in value2.unwrap_list().iter() {
+                    values.push(value1.clone().apply(value.clone(), interpreter)?);
+                }
+                Ok(Value::List(List::from(values)))
+            },
+        )))
+    }));
+
+    Module::new("map", r#type, value)
+}
@@ -1,14 +1,45 @@
 pub mod add;
+pub mod and;
 pub mod cat;
+pub mod char_code;
+pub mod chars;
+pub mod checked_div;
+pub mod compose;
+pub mod cons;
 pub mod dec;
 pub mod div;
+pub mod divmod;
+pub mod eq;
+pub mod filter;
+pub mod fold;
+pub mod from_chars;
+pub mod from_code;
+pub mod gt;
+pub mod gte;
+pub mod head;
 pub mod inc;
+pub mod length;
+pub mod lookup;
+pub mod lt;
+pub mod lte;
+pub mod map;
+pub mod modulo;
 pub mod mul;
+pub mod neq;
+pub mod not;
+pub mod or;
+pub mod parse_number;
+pub mod pow;
+pub mod range;
+pub mod reverse;
 pub mod sub;
+pub mod tail;
+pub mod to_string;
 
-use super::module::Module;
+use super::module::{DataType, Module};
 use crate::interpreter::r#type::Type;
 
 pub type CoreLibrary = Vec<Module>;
+pub type DataTypes = Vec<DataType>;
 pub type Primitive = (String, Type);
 pub type Primatives = Vec<Primitive>;
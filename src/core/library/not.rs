@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(Box::new(Type::Boolean), Box::new(Type::Boolean));
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        Ok(Value::Boolean(!value.unwrap_bool()))
+    }));
+
+    Module::new("not", r#type, value)
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{
+        r#type::Type,
+        value::{Thunk, Value},
+        Interpreter,
+    },
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Boolean),
+        Box::new(Type::Closure(
+            Box::new(Type::Boolean),
+            Box::new(Type::Boolean),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        let value1 = value1.unwrap_bool();
+        Ok(Value::Lazy(Arc::new(
+            move |value2: Thunk, interpreter: &mut Interpreter| {
+                if value1 {
+                    Ok(Value::Boolean(true))
+                } else {
+                    value2(interpreter)
+                }
+            },
+        )))
+    }));
+
+    Module::new("or", r#type, value)
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{number::Number, r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(Box::new(Type::String), Box::new(Type::option(Type::Number)));
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        let string = value.unwrap_string();
+        let source = string.trim();
+        let digits = source.strip_prefix('-').unwrap_or(source);
+        let valid = match digits.split_once('.') {
+            Some((int, fraction)) => is_digits(int) && is_digits(fraction),
+            None => is_digits(digits),
+        };
+
+        Ok(match valid {
+            true => Value::some(Value::Number(Number::parse(source))),
+            false => Value::none(),
+        })
+    }));
+
+    Module::new("parse_number", r#type, value)
+}
+
+fn is_digits(source: &str) -> bool {
+    !source.is_empty() && source.chars().all(|char| char.is_ascii_digit())
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    error::Error,
+    interpreter::{list::List, number::Number, r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::Number),
+        Box::new(Type::Closure(
+            Box::new(Type::Number),
+            Box::new(Type::list(Type::Number)),
+        )),
+    );
+
+    let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| {
+                let (value1, value2) = (value1.clone().unwrap_number(), value2.unwrap_number());
+                if !value1.is_integer() || !value2.is_integer() {
+                    return Err(Error::RuntimeError(
+                        0..0,
+                        String::from("Range bounds must be integers"),
+                    ));
+                }
+
+                let bounds = (value1.as_int(), value2.as_int());
+                let (start, end) = match bounds {
+                    (Some(start), Some(end)) => (start, end),
+                    _ => {
+                        return Err(Error::RuntimeError(
+                            0..0,
+                            String::from("Range bounds are too large"),
+                        ))
+                    }
+                };
+                let values: Vec<Value> = (start..end)
+                    .map(|int| Value::Number(Number::from(int)))
+                    .collect();
+                Ok(Value::List(List::from(values)))
+            },
+        )))
+    }));
+
+    Module::new("range", r#type, value)
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::list(Type::Generic(0))),
+        Box::new(Type::list(Type::Generic(0))),
+    );
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        Ok(Value::List(value.unwrap_list().reverse()))
+    }));
+
+    Module::new("reverse", r#type, value)
+}
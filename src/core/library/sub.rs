@@ -7,15 +7,22 @@ use crate::{
 
 pub fn define() -> Module {
     let r#type = Type::Closure(
-        box Type::Number,
-        box Type::Closure(box Type::Number, box Type::Number),
+        Box::new(Type::Number),
+        Box::new(Type::Closure(
+            Box::new(Type::Number),
+            Box::new(Type::Number),
+        )),
     );
 
     let value = Value::Closure(Arc::new(|value1: Value, _: &mut Interpreter| {
-        Value::Closure(Arc::new(move |value2: Value, _: &mut Interpreter| {
-            let value1 = value1.clone();
-            Value::Number(value1.unwrap_number() - value2.unwrap_number())
-        }))
+        Ok(Value::Closure(Arc::new(
+            move |value2: Value, _: &mut Interpreter| {
+                let value1 = value1.clone();
+                Ok(Value::Number(
+                    value1.unwrap_number() - value2.unwrap_number(),
+                ))
+            },
+        )))
     }));
 
     Module::new("sub", r#type, value)
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    error::Error,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(
+        Box::new(Type::list(Type::Generic(0))),
+        Box::new(Type::list(Type::Generic(0))),
+    );
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        match value.unwrap_list().tail() {
+            Some(tail) => Ok(Value::List(tail)),
+            None => Err(Error::RuntimeError(
+                0..0,
+                String::from("Cannot take the tail of an empty list"),
+            )),
+        }
+    }));
+
+    Module::new("tail", r#type, value)
+}
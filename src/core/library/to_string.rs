@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use crate::{
+    core::module::Module,
+    interpreter::{r#type::Type, value::Value, Interpreter},
+};
+
+pub fn define() -> Module {
+    let r#type = Type::Closure(Box::new(Type::Number), Box::new(Type::String));
+
+    let value = Value::Closure(Arc::new(|value: Value, _: &mut Interpreter| {
+        Ok(Value::String(format!("{:?}", value.unwrap_number())))
+    }));
+
+    Module::new("to_string", r#type, value)
+}
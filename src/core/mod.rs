@@ -4,7 +4,9 @@ mod module;
 use crate::interpreter::r#type::Type;
 
 use self::library::*;
+use self::module::DataType;
 use vec as core;
+use vec as data;
 use vec as primitives;
 
 pub fn build() -> CoreLibrary {
@@ -15,14 +17,236 @@ pub fn build() -> CoreLibrary {
         sub::define(),
         mul::define(),
         div::define(),
+        modulo::define(),
+        pow::define(),
+        divmod::define(),
         cat::define(),
+        eq::define(),
+        neq::define(),
+        lt::define(),
+        lte::define(),
+        gt::define(),
+        gte::define(),
+        and::define(),
+        or::define(),
+        not::define(),
+        map::define(),
+        filter::define(),
+        fold::define(),
+        length::define(),
+        head::define(),
+        tail::define(),
+        cons::define(),
+        range::define(),
+        reverse::define(),
+        compose::define(),
+        to_string::define(),
+        parse_number::define(),
+        checked_div::define(),
+        lookup::define(),
+        chars::define(),
+        from_chars::define(),
+        char_code::define(),
+        from_code::define(),
+    ]
+}
+
+pub fn data() -> DataTypes {
+    data![
+        DataType::new(
+            "Option",
+            1,
+            vec![("Some", vec![Type::Generic(0)]), ("None", vec![])]
+        ),
+        DataType::new(
+            "Result",
+            2,
+            vec![
+                ("Ok", vec![Type::Generic(1)]),
+                ("Err", vec![Type::Generic(0)])
+            ]
+        )
     ]
 }
 
 pub fn primitives() -> Primatives {
     primitives![
         ("String".into(), Type::String),
+        ("Char".into(), Type::Char),
         ("Number".into(), Type::Number),
-        ("Boolean".into(), Type::Boolean)
+        ("Boolean".into(), Type::Boolean),
+        ("Unit".into(), Type::Unit),
+        ("List".into(), Type::list(Type::Generic(0)))
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, run, value::Value},
+    };
+
+    fn boolean(source: &str) -> bool {
+        run(source).unwrap().unwrap_bool()
+    }
+
+    #[test]
+    fn compares_any_comparable_type() {
+        assert!(boolean("eq 1 1"));
+        assert!(boolean("neq \"a\" \"b\""));
+        assert!(boolean("lt 'a' 'b'"));
+        assert!(boolean("gte \"b\" \"a\""));
+        assert!(boolean("eq [1, 2] [1, 2]"));
+        assert!(boolean("lt [1, 2] [1, 3]"));
+        assert!(boolean("lte (1, \"b\") (1, \"b\")"));
+        assert!(boolean("gt { a = 2 } { a = 1 }"));
+        assert!(boolean("eq (Some 1) (Some 1)"));
+    }
+
+    #[test]
+    fn orders_variants_by_constructor() {
+        assert!(boolean("lt (Some 1) None"));
+        assert!(!boolean("gt (Some 1) None"));
+        assert!(!boolean("gte (Some 1) None"));
+        assert!(boolean("lt (Some 1) (Some 2)"));
+        assert!(boolean("gt (Err \"a\") (Ok 1)"));
+        assert!(boolean(
+            "type Size = Small | Large Number\nlt Small (Large 0)"
+        ));
+    }
+
+    #[test]
+    fn only_exposes_generic_comparisons() {
+        for name in [
+            "eq_string",
+            "neq_string",
+            "lt_string",
+            "lte_string",
+            "gt_string",
+            "gte_string",
+        ] {
+            assert!(matches!(
+                run(&format!("{} \"a\" \"b\"", name))
+                    .unwrap_err()
+                    .as_slice(),
+                [Error::UnknownName(_, _)]
+            ));
+        }
+    }
+
+    #[test]
+    fn refuses_to_compare_closures() {
+        for source in [
+            "eq inc inc",
+            "lt [inc] [inc]",
+            "same = a, b >> eq a b\nsame inc inc",
+            "same = a, b >> eq (a, 1) (b, 1)\nsame (x >> x) inc",
+            "same: a -> a -> Boolean = a, b >> lte a b\nsame inc inc",
+        ] {
+            assert!(matches!(
+                infer(source).unwrap_err().as_slice(),
+                [Error::Incomparable(_, _)]
+            ));
+        }
+
+        match infer("eq inc inc").unwrap_err().as_slice() {
+            [Error::Incomparable(range, _)] => assert_eq!(range.clone(), 0..2),
+            errors => panic!("Expected a comparison error, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn keeps_comparisons_generic() {
+        let same = "same = a, b >> eq a b\n";
+        assert!(boolean(&format!(
+            "{}and (same 1 1) (same \"a\" \"a\")",
+            same
+        )));
+        assert!(boolean("let same = a, b >> eq a b in same [1] [1]"));
+    }
+
+    #[test]
+    fn short_circuits_boolean_operators() {
+        assert!(!boolean("and false (eq (div 1 0) 1)"));
+        assert!(boolean("or true (eq (div 1 0) 1)"));
+        assert_eq!(run("not true").unwrap(), Value::Boolean(false));
+    }
+
+    #[test]
+    fn transforms_lists() {
+        assert_eq!(run("map inc [1, 2, 3]").unwrap(), run("[2, 3, 4]").unwrap());
+        assert_eq!(
+            run("filter (x >> x > 1) [1, 2, 3]").unwrap(),
+            run("[2, 3]").unwrap()
+        );
+        assert_eq!(run("fold add 0 [1, 2, 3, 4]").unwrap(), run("10").unwrap());
+        assert_eq!(
+            run("reverse (cons 0 [1, 2])").unwrap(),
+            run("[2, 1, 0]").unwrap()
+        );
+        assert_eq!(
+            run("length (range 0 200000)").unwrap(),
+            run("200000").unwrap()
+        );
+        assert_eq!(run("head (tail [5, 6, 7])").unwrap(), run("6").unwrap());
+    }
+
+    #[test]
+    fn refuses_to_split_empty_lists() {
+        for source in ["head []", "tail []"] {
+            match run(source).unwrap_err().as_slice() {
+                [Error::RuntimeError(range, _)] => assert_eq!(range.clone(), 0..7),
+                errors => panic!("Expected a runtime error, got {:?}", errors),
+            }
+        }
+    }
+
+    #[test]
+    fn refuses_fractional_range_bounds() {
+        for source in ["range 0.5 3", "range 0 2.5"] {
+            match run(source).unwrap_err().as_slice() {
+                [Error::RuntimeError(_, message)] => {
+                    assert_eq!(message, "Range bounds must be integers")
+                }
+                errors => panic!("Expected a runtime error, got {:?}", errors),
+            }
+        }
+        assert_eq!(run("range 1.0 3").unwrap(), run("[1, 2]").unwrap());
+    }
+
+    #[test]
+    fn returns_options_and_results() {
+        assert_eq!(
+            run("parse_number \"-4.5\"").unwrap(),
+            run("Some -4.5").unwrap()
+        );
+        assert_eq!(run("parse_number \"4x\"").unwrap(), run("None").unwrap());
+        assert_eq!(run("checked_div 6 3").unwrap(), run("Ok 2").unwrap());
+        assert_eq!(
+            run("checked_div 6 0").unwrap(),
+            run("Err \"Division by zero\"").unwrap()
+        );
+        assert_eq!(
+            run("lookup \"b\" [(\"a\", 1), (\"b\", 2)]").unwrap(),
+            run("Some 2").unwrap()
+        );
+        assert_eq!(run("lookup 3 [(1, \"x\")]").unwrap(), run("None").unwrap());
+    }
+
+    #[test]
+    fn converts_between_strings_characters_and_codes() {
+        assert_eq!(
+            run("chars \"abc\"").unwrap(),
+            run("['a', 'b', 'c']").unwrap()
+        );
+        assert_eq!(
+            run("from_chars (reverse (chars \"abc\"))").unwrap(),
+            Value::String(String::from("cba"))
+        );
+        assert_eq!(run("char_code 'A'").unwrap(), run("65").unwrap());
+        assert_eq!(run("from_code 97").unwrap(), run("Some 'a'").unwrap());
+        assert_eq!(run("from_code (0 - 1)").unwrap(), run("None").unwrap());
+        assert_eq!(run("from_code 55296").unwrap(), run("None").unwrap());
+    }
+}
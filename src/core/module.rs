@@ -5,6 +5,7 @@ pub struct Module {
     pub name: String,
     pub r#type: Type,
     pub value: Value,
+    pub comparable: Vec<usize>,
 }
 
 impl Module {
@@ -13,6 +14,31 @@ impl Module {
             name: String::from(name),
             r#type,
             value,
+            comparable: vec![],
+        }
+    }
+
+    pub fn comparing(mut self, generics: Vec<usize>) -> Module {
+        self.comparable = generics;
+        self
+    }
+}
+
+pub struct DataType {
+    pub name: String,
+    pub arity: usize,
+    pub variants: Vec<(String, Vec<Type>)>,
+}
+
+impl DataType {
+    pub fn new(name: &str, arity: usize, variants: Vec<(&str, Vec<Type>)>) -> DataType {
+        DataType {
+            name: String::from(name),
+            arity,
+            variants: variants
+                .into_iter()
+                .map(|(name, fields)| (String::from(name), fields))
+                .collect(),
         }
     }
 }
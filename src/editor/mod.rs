@@ -4,10 +4,10 @@ pub mod escape_code;
 use std::io::stdout;
 
 use crossterm::{
-    cursor::{Hide, MoveTo, MoveToNextLine, Show},
+    cursor::{Hide, MoveTo, Show},
     event::{read, Event, KeyCode, KeyEvent, KeyModifiers},
     style::{Print, Stylize},
-    terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType::All},
+    terminal::{disable_raw_mode, enable_raw_mode, Clear, ClearType::All},
     ExecutableCommand, Result,
 };
 
@@ -28,6 +28,12 @@ impl Module {
     }
 }
 
+impl Default for Module {
+    fn default() -> Self {
+        Module::new()
+    }
+}
+
 pub struct Editor {
     modules: Vec<Module>,
     current: usize,
@@ -66,6 +72,12 @@ impl Editor {
     }
 }
 
+impl Default for Editor {
+    fn default() -> Self {
+        Editor::new()
+    }
+}
+
 impl Editor {
     pub fn new() -> Editor {
         Editor {
@@ -82,7 +94,7 @@ impl Editor {
         std::panic::set_hook(Box::new(|info| {
             disable_raw_mode().unwrap();
             stdout().execute(Show).unwrap();
-            println!("{}", info.to_string());
+            println!("{}", info);
         }));
 
         loop {
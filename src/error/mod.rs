@@ -5,23 +5,44 @@ use std::ops::Range;
 
 use crate::editor::escape_code::*;
 use crate::interpreter::r#type::*;
+use crate::interpreter::value::Value;
 use crate::syntax::*;
 
 #[derive(Clone)]
 pub enum Error {
     UnknownToken(Range<usize>, String),
+    UnterminatedComment(Range<usize>),
+    UnterminatedString(Range<usize>),
+    InvalidEscape(Range<usize>, String),
+    UnterminatedInterpolation(Range<usize>),
+    EmptyInterpolation(Range<usize>),
+    InvalidCharacter(Range<usize>),
     UnknownName(Range<usize>, String),
-    UnexpectedType(Range<usize>, Type, Type),
+    UsedBeforeDefinition(Range<usize>, String),
+    UnexpectedType(Range<usize>, Box<Type>, Box<Type>),
     Reassignment(Range<usize>, String),
     UnexpectedToken(Range<usize>, Token, Token),
     BadCall(Range<usize>),
-    MismatchedTypeAssignment(Range<usize>, String, Type, Type),
+    MismatchedTypeAssignment(Range<usize>, String, Box<Type>, Box<Type>),
+    NotGeneric(Range<usize>, String, Box<Type>),
+    UnknownType(Range<usize>, String),
+    TypeRedefinition(Range<usize>, String),
+    WrongArguments(Range<usize>, String, usize, usize),
+    NonExhaustiveMatch(Range<usize>, String),
+    UnknownField(Range<usize>, String, Box<Type>),
+    DuplicateField(Range<usize>, String),
+    AmbiguousAccess(Range<usize>, String),
+    Incomparable(Range<usize>, Box<Type>),
+    RuntimeError(Range<usize>, String),
+    PropagationOutsideClosure(Range<usize>),
+    Propagation(Value),
 }
 
 #[derive(Debug)]
 pub enum Category {
     Syntax,
     Type,
+    Runtime,
 }
 
 impl Error {
@@ -38,18 +59,21 @@ impl Error {
         )
     }
 
-    pub fn is_unexpected_end_of_file(&self) -> bool {
+    pub fn locate(self, range: Range<usize>) -> Error {
         match self {
-            Error::UnexpectedToken(_, _, token) => {
-                if token == &Token::EndOfFile {
-                    true
-                } else {
-                    false
-                }
+            Error::RuntimeError(location, message) if location.is_empty() => {
+                Error::RuntimeError(range, message)
             }
-            _ => false,
+            error => error,
         }
     }
+
+    pub fn is_unexpected_end_of_file(&self) -> bool {
+        matches!(
+            self,
+            Error::UnexpectedToken(_, _, Token::EndOfFile) | Error::UnterminatedComment(_)
+        )
+    }
 }
 
 impl Debug for Error {
@@ -61,6 +85,12 @@ impl Debug for Error {
                 range.clone(),
                 format!("Unknown name \"{}\"", name),
             ),
+            Self::UsedBeforeDefinition(range, name) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!("\"{}\" was used before it was defined", name),
+            ),
             Self::UnexpectedType(range, expected, recieved) => Error::log(
                 f,
                 Category::Type,
@@ -79,6 +109,42 @@ impl Debug for Error {
                 range.clone(),
                 format!("Unknown token {}", name),
             ),
+            Self::UnterminatedComment(range) => Error::log(
+                f,
+                Category::Syntax,
+                range.clone(),
+                String::from("Unterminated block comment"),
+            ),
+            Self::UnterminatedString(range) => Error::log(
+                f,
+                Category::Syntax,
+                range.clone(),
+                String::from("Unterminated string"),
+            ),
+            Self::InvalidEscape(range, escape) => Error::log(
+                f,
+                Category::Syntax,
+                range.clone(),
+                format!("Invalid escape sequence {}", escape),
+            ),
+            Self::UnterminatedInterpolation(range) => Error::log(
+                f,
+                Category::Syntax,
+                range.clone(),
+                String::from("Unterminated interpolation"),
+            ),
+            Self::EmptyInterpolation(range) => Error::log(
+                f,
+                Category::Syntax,
+                range.clone(),
+                String::from("Empty interpolation"),
+            ),
+            Self::InvalidCharacter(range) => Error::log(
+                f,
+                Category::Syntax,
+                range.clone(),
+                String::from("Invalid character literal"),
+            ),
             Self::UnexpectedToken(range, expected, recieved) => Error::log(
                 f,
                 Category::Syntax,
@@ -100,6 +166,84 @@ impl Debug for Error {
                     expression_type, name, name_type
                 ),
             ),
+            Self::UnknownType(range, name) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!("Unknown type \"{}\"", name),
+            ),
+            Self::TypeRedefinition(range, name) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!("Cannot redefine type \"{}\"", name),
+            ),
+            Self::WrongArguments(range, name, expected, recieved) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!(
+                    "\"{}\" expects {} argument(s), recieved {}",
+                    name, expected, recieved
+                ),
+            ),
+            Self::RuntimeError(range, message) => {
+                Error::log(f, Category::Runtime, range.clone(), message.clone())
+            }
+            Self::UnknownField(range, name, r#type) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!("Type {:?} has no field \"{}\"", r#type, name),
+            ),
+            Self::AmbiguousAccess(range, name) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!(
+                    "Cannot infer which record has the field \"{}\", annotate the record parameter",
+                    name
+                ),
+            ),
+            Self::Incomparable(range, r#type) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!("Cannot compare values of type {:?}", r#type),
+            ),
+            Self::DuplicateField(range, name) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!("Field \"{}\" is defined more than once", name),
+            ),
+            Self::NonExhaustiveMatch(range, pattern) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!("Match is not exhaustive, {} is not covered", pattern),
+            ),
+            Self::NotGeneric(range, name, r#type) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                format!(
+                    "Type variable \"{}\" is not generic, it must be a {:?}",
+                    name, r#type
+                ),
+            ),
+            Self::PropagationOutsideClosure(range) => Error::log(
+                f,
+                Category::Type,
+                range.clone(),
+                String::from("Cannot propagate with ? outside of a closure"),
+            ),
+            Self::Propagation(value) => Error::log(
+                f,
+                Category::Runtime,
+                0..0,
+                format!("Propagated {:?} outside of a closure", value),
+            ),
         }
     }
 }
@@ -0,0 +1,370 @@
+use std::cmp::Ordering;
+use std::fmt::*;
+
+const BASE: u64 = 1_000_000_000;
+const WIDTH: usize = 9;
+
+#[derive(Clone, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    digits: Vec<u32>,
+}
+
+impl BigInt {
+    fn new(negative: bool, mut digits: Vec<u32>) -> BigInt {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        BigInt {
+            negative: negative && !digits.is_empty(),
+            digits,
+        }
+    }
+
+    pub fn parse(source: &str) -> BigInt {
+        let decimals: Vec<u32> = source
+            .chars()
+            .filter_map(|char| char.to_digit(10))
+            .collect();
+        let digits = decimals
+            .rchunks(WIDTH)
+            .map(|chunk| chunk.iter().fold(0, |digit, decimal| digit * 10 + decimal))
+            .collect();
+
+        BigInt::new(source.starts_with('-'), digits)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self
+            .digits
+            .iter()
+            .rev()
+            .fold(0.0, |float, digit| float * BASE as f64 + *digit as f64);
+
+        match self.negative {
+            true => -magnitude,
+            false => magnitude,
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        let magnitude = self.digits.iter().rev().try_fold(0i128, |int, digit| {
+            int.checked_mul(BASE as i128)?.checked_add(*digit as i128)
+        })?;
+
+        match self.negative {
+            true => i64::try_from(-magnitude).ok(),
+            false => i64::try_from(magnitude).ok(),
+        }
+    }
+
+    pub fn neg(&self) -> BigInt {
+        BigInt::new(!self.negative, self.digits.clone())
+    }
+
+    pub fn add(&self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, add_digits(&self.digits, &other.digits));
+        }
+
+        match compare_digits(&self.digits, &other.digits) {
+            Ordering::Less => BigInt::new(other.negative, sub_digits(&other.digits, &self.digits)),
+            _ => BigInt::new(self.negative, sub_digits(&self.digits, &other.digits)),
+        }
+    }
+
+    pub fn sub(&self, other: &BigInt) -> BigInt {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            mul_digits(&self.digits, &other.digits),
+        )
+    }
+
+    pub fn div_rem_euclid(&self, other: &BigInt) -> (BigInt, BigInt) {
+        let (quotient, remainder) = div_digits(&self.digits, &other.digits);
+        let quotient = BigInt::new(self.negative != other.negative, quotient);
+        let remainder = BigInt::new(self.negative, remainder);
+
+        if !remainder.is_negative() {
+            return (quotient, remainder);
+        }
+
+        let one = BigInt::from(1);
+        match other.is_negative() {
+            true => (quotient.add(&one), remainder.sub(other)),
+            false => (quotient.sub(&one), remainder.add(other)),
+        }
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> BigInt {
+        let mut base = self.clone();
+        let mut result = BigInt::from(1);
+
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = result.mul(&base);
+            }
+            exponent /= 2;
+            if exponent > 0 {
+                base = base.mul(&base);
+            }
+        }
+
+        result
+    }
+}
+
+fn compare_digits(digits1: &[u32], digits2: &[u32]) -> Ordering {
+    digits1
+        .len()
+        .cmp(&digits2.len())
+        .then_with(|| digits1.iter().rev().cmp(digits2.iter().rev()))
+}
+
+fn add_digits(digits1: &[u32], digits2: &[u32]) -> Vec<u32> {
+    let mut digits = vec![];
+    let mut carry = 0;
+
+    for index in 0..digits1.len().max(digits2.len()) {
+        let sum = carry
+            + *digits1.get(index).unwrap_or(&0) as u64
+            + *digits2.get(index).unwrap_or(&0) as u64;
+        digits.push((sum % BASE) as u32);
+        carry = sum / BASE;
+    }
+
+    if carry > 0 {
+        digits.push(carry as u32);
+    }
+
+    digits
+}
+
+fn sub_digits(digits1: &[u32], digits2: &[u32]) -> Vec<u32> {
+    let mut digits = vec![];
+    let mut borrow = 0;
+
+    for (index, digit) in digits1.iter().enumerate() {
+        let subtrahend = borrow + *digits2.get(index).unwrap_or(&0) as i64;
+        let difference = *digit as i64 - subtrahend;
+
+        match difference < 0 {
+            true => {
+                digits.push((difference + BASE as i64) as u32);
+                borrow = 1;
+            }
+            false => {
+                digits.push(difference as u32);
+                borrow = 0;
+            }
+        }
+    }
+
+    digits
+}
+
+fn mul_digits(digits1: &[u32], digits2: &[u32]) -> Vec<u32> {
+    let mut digits = vec![0u64; digits1.len() + digits2.len()];
+
+    for (index1, digit1) in digits1.iter().enumerate() {
+        let mut carry = 0;
+
+        for (index2, digit2) in digits2.iter().enumerate() {
+            let product = digits[index1 + index2] + *digit1 as u64 * *digit2 as u64 + carry;
+            digits[index1 + index2] = product % BASE;
+            carry = product / BASE;
+        }
+
+        digits[index1 + digits2.len()] += carry;
+    }
+
+    let mut digits: Vec<u32> = digits.into_iter().map(|digit| digit as u32).collect();
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    digits
+}
+
+fn div_digits(digits1: &[u32], digits2: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut quotient = vec![];
+    let mut remainder: Vec<u32> = vec![];
+
+    for digit in digits1.iter().rev() {
+        remainder.insert(0, *digit);
+        while remainder.last() == Some(&0) {
+            remainder.pop();
+        }
+
+        let (mut low, mut high) = (0, BASE as u32 - 1);
+        while low < high {
+            let middle = low + (high - low).div_ceil(2);
+            match compare_digits(&mul_digits(digits2, &[middle]), &remainder) {
+                Ordering::Greater => high = middle - 1,
+                _ => low = middle,
+            }
+        }
+
+        remainder = sub_digits(&remainder, &mul_digits(digits2, &[low]));
+        while remainder.last() == Some(&0) {
+            remainder.pop();
+        }
+        quotient.push(low);
+    }
+
+    quotient.reverse();
+    (quotient, remainder)
+}
+
+impl From<i64> for BigInt {
+    fn from(int: i64) -> BigInt {
+        let mut magnitude = int.unsigned_abs();
+        let mut digits = vec![];
+
+        while magnitude > 0 {
+            digits.push((magnitude % BASE) as u32);
+            magnitude /= BASE;
+        }
+
+        BigInt::new(int < 0, digits)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_digits(&self.digits, &other.digits),
+            (true, true) => compare_digits(&other.digits, &self.digits),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        match self.digits.split_last() {
+            Some((last, rest)) => {
+                write!(f, "{}", last)?;
+                for digit in rest.iter().rev() {
+                    write!(f, "{:0width$}", digit, width = WIDTH)?;
+                }
+                Ok(())
+            }
+            None => write!(f, "0"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int(source: &str) -> BigInt {
+        BigInt::parse(source)
+    }
+
+    fn show(int: BigInt) -> String {
+        format!("{:?}", int)
+    }
+
+    #[test]
+    fn parses_and_prints_large_integers() {
+        assert_eq!(
+            show(int("123456789012345678901234567890")),
+            "123456789012345678901234567890"
+        );
+        assert_eq!(show(int("-000000000000000000000000000005")), "-5");
+        assert_eq!(show(int("-0")), "0");
+        assert_eq!(int("1000000000"), BigInt::from(1_000_000_000));
+        assert_eq!(BigInt::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!(int("9223372036854775808").to_i64(), None);
+    }
+
+    #[test]
+    fn adds_and_subtracts_across_digits() {
+        assert_eq!(
+            show(int("9223372036854775807").add(&BigInt::from(1))),
+            "9223372036854775808"
+        );
+        assert_eq!(
+            show(BigInt::from(1_000_000_000).sub(&BigInt::from(1))),
+            "999999999"
+        );
+        assert_eq!(
+            show(BigInt::from(0).sub(&int("1000000000000000000000000000000"))),
+            "-1000000000000000000000000000000"
+        );
+        assert_eq!(show(BigInt::from(5).add(&BigInt::from(-5))), "0");
+    }
+
+    #[test]
+    fn multiplies_and_raises_to_powers() {
+        assert_eq!(
+            show(int("999999999999").mul(&int("999999999999"))),
+            "999999999998000000000001"
+        );
+        assert_eq!(
+            show(BigInt::from(2).pow(100)),
+            "1267650600228229401496703205376"
+        );
+        assert_eq!(show(BigInt::from(0).pow(0)), "1");
+        assert_eq!(show(BigInt::from(-3).mul(&BigInt::from(3))), "-9");
+    }
+
+    #[test]
+    fn divides_with_euclidean_remainders() {
+        for (dividend, divisor, quotient, remainder) in
+            [(-7, 2, -4, 1), (7, -2, -3, 1), (-7, -2, 4, 1), (7, 2, 3, 1)]
+        {
+            let (actual_quotient, actual_remainder) =
+                BigInt::from(dividend).div_rem_euclid(&BigInt::from(divisor));
+            assert_eq!(actual_quotient, BigInt::from(quotient));
+            assert_eq!(actual_remainder, BigInt::from(remainder));
+        }
+
+        let (dividend, divisor) = (123456789123456789i128, 987654321i128);
+        let (quotient, remainder) =
+            int(&dividend.to_string()).div_rem_euclid(&int(&divisor.to_string()));
+        assert_eq!(show(quotient), (dividend / divisor).to_string());
+        assert_eq!(show(remainder), (dividend % divisor).to_string());
+
+        let (quotient, remainder) = BigInt::from(10).pow(25).div_rem_euclid(&BigInt::from(3));
+        assert_eq!(show(quotient), "3333333333333333333333333");
+        assert_eq!(show(remainder), "1");
+    }
+
+    #[test]
+    fn orders_by_sign_and_magnitude() {
+        assert!(BigInt::from(-1) > BigInt::from(0).sub(&BigInt::from(10).pow(30)));
+        assert!(BigInt::from(10).pow(30) < BigInt::from(10).pow(31));
+        assert!(BigInt::from(-2) < BigInt::from(-1));
+        assert_eq!(
+            BigInt::from(10).pow(20),
+            BigInt::from(10).pow(10).mul(&BigInt::from(10).pow(10))
+        );
+    }
+}
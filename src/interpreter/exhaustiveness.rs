@@ -0,0 +1,224 @@
+use std::fmt::*;
+
+use super::*;
+
+#[derive(Clone, PartialEq)]
+pub enum Pattern {
+    Any,
+    Constructor(String, Vec<Pattern>),
+    Literal(String),
+}
+
+impl Debug for Pattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Pattern::Any => write!(f, "_"),
+            Pattern::Constructor(name, elements) if name.starts_with(',') => {
+                write!(f, "(")?;
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}", element)?;
+                }
+                write!(f, ")")
+            }
+            Pattern::Constructor(name, arguments) => {
+                write!(f, "{}", name)?;
+                for argument in arguments {
+                    match argument {
+                        Pattern::Constructor(name, arguments)
+                            if !arguments.is_empty() && !name.starts_with(',') =>
+                        {
+                            write!(f, " ({:?})", argument)?
+                        }
+                        _ => write!(f, " {:?}", argument)?,
+                    }
+                }
+                Ok(())
+            }
+            Pattern::Literal(literal) => write!(f, "{}", literal),
+        }
+    }
+}
+
+impl Interpreter {
+    fn signature(&self, scope: ScopeIndex, name: &str) -> Option<Vec<(String, usize)>> {
+        match name {
+            "true" | "false" => Some(vec![(String::from("true"), 0), (String::from("false"), 0)]),
+            "none" => Some(vec![(String::from("none"), 0)]),
+            _ if name.starts_with(',') => Some(vec![(String::from(name), name.len() + 1)]),
+            _ => self.lookup_constructor(scope, name).map(|constructor| {
+                constructor
+                    .siblings
+                    .iter()
+                    .map(|sibling| match self.lookup_constructor(scope, sibling) {
+                        Some(constructor) => (sibling.clone(), constructor.arity),
+                        None => (sibling.clone(), 0),
+                    })
+                    .collect()
+            }),
+        }
+    }
+
+    pub fn missing(
+        &self,
+        scope: ScopeIndex,
+        rows: &[Vec<Pattern>],
+        width: usize,
+    ) -> Option<Vec<Pattern>> {
+        if width == 0 {
+            return match rows.is_empty() {
+                true => Some(vec![]),
+                false => None,
+            };
+        }
+
+        let heads: Vec<&Pattern> = rows
+            .iter()
+            .map(|row| &row[0])
+            .filter(|head| **head != Pattern::Any)
+            .collect();
+        let signature = match heads.first() {
+            Some(Pattern::Constructor(name, _)) => self.signature(scope, name),
+            _ => None,
+        };
+
+        if let Some(signature) = &signature {
+            let is_complete = signature.iter().all(|(name, _)| {
+                heads
+                    .iter()
+                    .any(|head| matches!(head, Pattern::Constructor(head, _) if head == name))
+            });
+
+            if is_complete {
+                for (name, arity) in signature {
+                    let rows = specialize(rows, name, *arity);
+
+                    if let Some(witness) = self.missing(scope, &rows, arity + width - 1) {
+                        let (arguments, rest) = witness.split_at(*arity);
+                        let mut witness =
+                            vec![Pattern::Constructor(name.clone(), arguments.to_vec())];
+                        witness.extend(rest.to_vec());
+                        return Some(witness);
+                    }
+                }
+
+                return None;
+            }
+        }
+
+        let rows: Vec<Vec<Pattern>> = rows
+            .iter()
+            .filter(|row| row[0] == Pattern::Any)
+            .map(|row| row[1..].to_vec())
+            .collect();
+        let rest = self.missing(scope, &rows, width - 1)?;
+
+        let head = match signature {
+            Some(signature) => signature
+                .into_iter()
+                .find(|(name, _)| {
+                    !heads
+                        .iter()
+                        .any(|head| matches!(head, Pattern::Constructor(head, _) if head == name))
+                })
+                .map(|(name, arity)| Pattern::Constructor(name, vec![Pattern::Any; arity]))
+                .unwrap_or(Pattern::Any),
+            None => Pattern::Any,
+        };
+
+        let mut witness = vec![head];
+        witness.extend(rest);
+        Some(witness)
+    }
+}
+
+fn specialize(rows: &[Vec<Pattern>], name: &str, arity: usize) -> Vec<Vec<Pattern>> {
+    rows.iter()
+        .filter_map(|row| {
+            let mut specialized = match &row[0] {
+                Pattern::Constructor(head, arguments) if head == name => arguments.clone(),
+                Pattern::Any => vec![Pattern::Any; arity],
+                _ => return None,
+            };
+            specialized.extend(row[1..].to_vec());
+            Some(specialized)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constructor(name: &str, arguments: Vec<Pattern>) -> Pattern {
+        Pattern::Constructor(String::from(name), arguments)
+    }
+
+    fn missing(rows: Vec<Pattern>) -> Option<String> {
+        let interpreter = Interpreter::new();
+        let rows: Vec<Vec<Pattern>> = rows.into_iter().map(|row| vec![row]).collect();
+        interpreter
+            .missing(0, &rows, 1)
+            .map(|witness| format!("{:?}", witness[0]))
+    }
+
+    #[test]
+    fn accepts_complete_matches() {
+        assert_eq!(missing(vec![Pattern::Any]), None);
+        assert_eq!(
+            missing(vec![
+                constructor("true", vec![]),
+                constructor("false", vec![])
+            ]),
+            None
+        );
+        assert_eq!(
+            missing(vec![
+                constructor("Some", vec![Pattern::Any]),
+                constructor("None", vec![]),
+            ]),
+            None
+        );
+        assert_eq!(
+            missing(vec![Pattern::Literal(String::from("1")), Pattern::Any]),
+            None
+        );
+    }
+
+    #[test]
+    fn finds_missing_constructors() {
+        assert_eq!(missing(vec![]), Some(String::from("_")));
+        assert_eq!(
+            missing(vec![constructor("true", vec![])]),
+            Some(String::from("false"))
+        );
+        assert_eq!(
+            missing(vec![constructor("Some", vec![Pattern::Any])]),
+            Some(String::from("None"))
+        );
+        assert_eq!(
+            missing(vec![Pattern::Literal(String::from("1"))]),
+            Some(String::from("_"))
+        );
+    }
+
+    #[test]
+    fn finds_missing_nested_patterns() {
+        assert_eq!(
+            missing(vec![
+                constructor("Some", vec![constructor("true", vec![])]),
+                constructor("None", vec![]),
+            ]),
+            Some(String::from("Some false"))
+        );
+        assert_eq!(
+            missing(vec![constructor(
+                ",",
+                vec![Pattern::Any, constructor("true", vec![])]
+            )]),
+            Some(String::from("(_, false)"))
+        );
+    }
+}
@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+use std::ops::Range;
+
+use super::*;
+
+impl Interpreter {
+    pub fn fresh(&mut self) -> Type {
+        self.substitution.push(None);
+        Type::Variable(self.substitution.len() - 1)
+    }
+
+    pub fn prune(&self, r#type: Type) -> Type {
+        match r#type {
+            Type::Variable(id) => match &self.substitution[id] {
+                Some(r#type) => self.prune(r#type.clone()),
+                None => Type::Variable(id),
+            },
+            r#type => r#type,
+        }
+    }
+
+    pub fn resolve(&self, r#type: Type) -> Type {
+        self.prune(r#type).map(|child| self.resolve(child))
+    }
+
+    pub fn readable<const N: usize>(&self, types: [Type; N]) -> [Type; N] {
+        let types = types.map(|r#type| self.resolve(r#type));
+        let mut generics = HashMap::new();
+
+        for id in types.iter().flat_map(free_variables) {
+            let generic = Type::Generic(generics.len());
+            generics.entry(id).or_insert(generic);
+        }
+
+        types.map(|r#type| substitute(r#type, &generics))
+    }
+
+    pub fn instantiate(&mut self, r#type: Type) -> Type {
+        let mut instances = HashMap::new();
+        self.instantiate_with(r#type, &mut instances)
+    }
+
+    pub fn instantiate_name(&mut self, scope: ScopeIndex, node: Node, r#type: Type) -> Type {
+        let mut instances = HashMap::new();
+        let r#type = self.instantiate_with(r#type, &mut instances);
+
+        for id in self.lookup_comparable(scope, node) {
+            if let Some(instance) = instances.get(&id) {
+                self.comparisons.push((instance.clone(), self.range(node)));
+            }
+        }
+
+        r#type
+    }
+
+    fn instantiate_with(&mut self, r#type: Type, instances: &mut HashMap<usize, Type>) -> Type {
+        match r#type {
+            Type::Generic(id) => match instances.get(&id) {
+                Some(r#type) => r#type.clone(),
+                None => {
+                    let variable = self.fresh();
+                    instances.insert(id, variable.clone());
+                    variable
+                }
+            },
+            r#type => r#type.map(|child| self.instantiate_with(child, instances)),
+        }
+    }
+
+    pub fn generalize(&self, scope: ScopeIndex, r#type: Type) -> Type {
+        let r#type = self.resolve(r#type);
+        let generics = self.generics(scope, &r#type);
+        substitute(r#type, &generics)
+    }
+
+    fn generics(&self, scope: ScopeIndex, r#type: &Type) -> HashMap<usize, Type> {
+        let environment = self.environment_variables(scope);
+        let mut generics = HashMap::new();
+
+        for id in free_variables(r#type) {
+            if !environment.contains(&id) && !generics.contains_key(&id) {
+                generics.insert(id, Type::Generic(generics.len()));
+            }
+        }
+
+        generics
+    }
+
+    pub fn generalize_comparisons(&mut self, scope: ScopeIndex, node: Node, r#type: Type) {
+        let generics = self.generics(scope, &self.resolve(r#type));
+        let mut comparable = vec![];
+
+        for (compared, range) in std::mem::take(&mut self.comparisons) {
+            let compared = self.resolve(compared);
+            let variables = free_variables(&compared);
+            let generalized: Vec<usize> = variables
+                .iter()
+                .filter_map(|id| match generics.get(id) {
+                    Some(Type::Generic(index)) => Some(*index),
+                    _ => None,
+                })
+                .collect();
+
+            if generalized.len() < variables.len() || contains_closure(&compared) {
+                self.comparisons.push((compared, range));
+            }
+
+            comparable.extend(generalized);
+        }
+
+        if !comparable.is_empty() {
+            let source = self.source(node);
+            self.chain[scope].comparable_map.insert(source, comparable);
+        }
+    }
+
+    fn environment_variables(&self, scope: ScopeIndex) -> Vec<usize> {
+        let mut variables = vec![];
+        let mut current = Some(scope);
+
+        while let Some(scope) = current {
+            for r#type in self.chain[scope].type_map.values() {
+                variables.extend(free_variables(&self.resolve(r#type.clone())));
+            }
+            current = self.chain[scope].parent;
+        }
+
+        variables
+    }
+
+    pub fn check_generic(&mut self, scope: ScopeIndex, range: Range<usize>) {
+        let mut seen = vec![];
+        let mut definitions: Vec<(String, Type)> = self.chain[scope]
+            .type_definition_map
+            .iter()
+            .filter(|(_, r#type)| matches!(r#type, Type::Variable(_)))
+            .map(|(name, r#type)| (name.clone(), r#type.clone()))
+            .collect();
+        definitions.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (name, r#type) in definitions {
+            match self.prune(r#type) {
+                Type::Variable(id) if !seen.contains(&id) => seen.push(id),
+                r#type => {
+                    let [r#type] = self.readable([r#type]);
+                    self.error(Error::NotGeneric(range.clone(), name, Box::new(r#type)))
+                }
+            }
+        }
+    }
+
+    fn occurs(&self, id: usize, r#type: &Type) -> bool {
+        match self.prune(r#type.clone()) {
+            Type::Variable(other) => id == other,
+            r#type => r#type
+                .children()
+                .into_iter()
+                .any(|child| self.occurs(id, child)),
+        }
+    }
+
+    pub fn unify(&mut self, expected: Type, received: Type) -> bool {
+        match (self.prune(expected), self.prune(received)) {
+            (Type::Error, _) | (_, Type::Error) => true,
+            (Type::Variable(a), Type::Variable(b)) if a == b => true,
+            (Type::Variable(id), r#type) | (r#type, Type::Variable(id)) => {
+                if self.occurs(id, &r#type) {
+                    return false;
+                }
+
+                self.substitution[id] = Some(r#type);
+                true
+            }
+            (Type::Closure(param1, return1), Type::Closure(param2, return2)) => {
+                self.unify(*param1, *param2) && self.unify(*return1, *return2)
+            }
+            (Type::Data(name1, arguments1), Type::Data(name2, arguments2)) => {
+                name1 == name2
+                    && arguments1.len() == arguments2.len()
+                    && arguments1
+                        .into_iter()
+                        .zip(arguments2)
+                        .all(|(argument1, argument2)| self.unify(argument1, argument2))
+            }
+            (Type::Tuple(elements1), Type::Tuple(elements2)) => {
+                elements1.len() == elements2.len()
+                    && elements1
+                        .into_iter()
+                        .zip(elements2)
+                        .all(|(element1, element2)| self.unify(element1, element2))
+            }
+            (Type::Record(fields1), Type::Record(fields2)) => {
+                fields1.len() == fields2.len()
+                    && fields1
+                        .into_iter()
+                        .zip(fields2)
+                        .all(|((name1, field1), (name2, field2))| {
+                            name1 == name2 && self.unify(field1, field2)
+                        })
+            }
+            (expected, received) => expected == received,
+        }
+    }
+
+    pub fn check_accesses(&mut self) {
+        for (r#type, name, field, range) in std::mem::take(&mut self.accesses) {
+            let r#type = self.resolve(r#type);
+            let found = match &r#type {
+                Type::Record(fields) => fields
+                    .iter()
+                    .find(|(other, _)| other == &name)
+                    .map(|(_, found)| found.clone()),
+                _ => None,
+            };
+
+            match found {
+                Some(found) => {
+                    self.expect(found, field, range);
+                }
+                None if r#type == Type::Error => (),
+                None if matches!(r#type, Type::Variable(_)) => {
+                    self.error(Error::AmbiguousAccess(range, name))
+                }
+                None => {
+                    let [r#type] = self.readable([r#type]);
+                    self.error(Error::UnknownField(range, name, Box::new(r#type)))
+                }
+            }
+        }
+    }
+
+    pub fn check_comparisons(&mut self) {
+        for (compared, range) in std::mem::take(&mut self.comparisons) {
+            let compared = self.resolve(compared);
+
+            if contains_closure(&compared) {
+                let [compared] = self.readable([compared]);
+                self.error(Error::Incomparable(range, Box::new(compared)));
+            } else if !free_variables(&compared).is_empty() {
+                self.comparisons.push((compared, range));
+            }
+        }
+    }
+
+    pub fn expect(&mut self, expected: Type, received: Type, range: Range<usize>) -> bool {
+        if self.unify(expected.clone(), received.clone()) {
+            return true;
+        }
+
+        let [expected, received] = self.readable([expected, received]);
+        self.error(Error::UnexpectedType(
+            range,
+            Box::new(expected),
+            Box::new(received),
+        ));
+
+        false
+    }
+}
+
+pub fn substitute(r#type: Type, map: &HashMap<usize, Type>) -> Type {
+    match r#type {
+        Type::Variable(id) => match map.get(&id) {
+            Some(r#type) => r#type.clone(),
+            None => Type::Variable(id),
+        },
+        r#type => r#type.map(|child| substitute(child, map)),
+    }
+}
+
+fn contains_closure(r#type: &Type) -> bool {
+    match r#type {
+        Type::Closure(_, _) => true,
+        r#type => r#type.children().into_iter().any(contains_closure),
+    }
+}
+
+fn free_variables(r#type: &Type) -> Vec<usize> {
+    match r#type {
+        Type::Variable(id) => vec![*id],
+        r#type => r#type
+            .children()
+            .into_iter()
+            .flat_map(free_variables)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::{infer, number::Number, run};
+
+    fn closure(param: Type, r#return: Type) -> Type {
+        Type::Closure(Box::new(param), Box::new(r#return))
+    }
+
+    #[test]
+    fn unifies_variables_with_types() {
+        let mut interpreter = Interpreter::new();
+        let variable = interpreter.fresh();
+
+        assert!(interpreter.unify(
+            closure(variable.clone(), Type::Number),
+            closure(Type::String, Type::Number)
+        ));
+        assert_eq!(interpreter.prune(variable.clone()), Type::String);
+        assert!(!interpreter.unify(variable, Type::Number));
+    }
+
+    #[test]
+    fn refuses_infinite_types() {
+        let mut interpreter = Interpreter::new();
+        let variable = interpreter.fresh();
+
+        assert!(!interpreter.unify(variable.clone(), closure(variable, Type::Number)));
+    }
+
+    #[test]
+    fn infers_closure_parameters() {
+        assert_eq!(
+            infer("x >> add x 1").unwrap(),
+            closure(Type::Number, Type::Number)
+        );
+
+        let r#type = infer("f >> x >> f (f x)").unwrap();
+        let variable = Type::Variable(free_variables(&r#type)[0]);
+        let endomorphism = closure(variable.clone(), variable);
+        assert_eq!(r#type, closure(endomorphism.clone(), endomorphism));
+
+        assert_eq!(
+            run("fact = n >> if eq n 0 then 1 else mul n (fact (dec n))\nfact 5").unwrap(),
+            Value::Number(Number::from(120))
+        );
+    }
+
+    #[test]
+    fn reports_conflicting_uses_of_a_parameter() {
+        match infer("x >> cat x (inc x)").unwrap_err().as_slice() {
+            [Error::UnexpectedType(range, expected, received), ..] => {
+                assert_eq!(range.clone(), 16..17);
+                assert_eq!(**expected, Type::Number);
+                assert_eq!(**received, Type::String);
+            }
+            errors => panic!("Expected a type error, got {:?}", errors),
+        }
+
+        assert!(matches!(
+            infer("f >> f f").unwrap_err().as_slice(),
+            [Error::UnexpectedType(_, _, _)]
+        ));
+        assert!(matches!(
+            infer("1 2").unwrap_err().as_slice(),
+            [Error::BadCall(_)]
+        ));
+    }
+
+    #[test]
+    fn generalizes_and_instantiates_free_variables() {
+        let mut interpreter = Interpreter::new();
+        let variable = interpreter.fresh();
+        let generic = interpreter.generalize(0, closure(variable.clone(), variable));
+        assert_eq!(generic, closure(Type::Generic(0), Type::Generic(0)));
+
+        match interpreter.instantiate(generic) {
+            Type::Closure(param, r#return) => {
+                assert!(matches!(*param, Type::Variable(_)));
+                assert_eq!(param, r#return);
+            }
+            r#type => panic!("Expected a closure, got {:?}", r#type),
+        }
+    }
+
+    #[test]
+    fn uses_generic_bindings_at_different_types() {
+        assert_eq!(
+            run("id = x >> x\nn = inc (id 1)\ncat (id \"a\") \"b\"").unwrap(),
+            Value::String(String::from("ab"))
+        );
+        assert_eq!(
+            run("apply: (a -> b) -> a -> b = f >> x >> f x\napply inc 1").unwrap(),
+            Value::Number(Number::from(2))
+        );
+        assert_eq!(
+            run("chain = f >> g >> x >> g (f x)\ns = chain (cat \"a\") (cat \"b\")\ns \"c\"")
+                .unwrap(),
+            Value::String(String::from("bac"))
+        );
+    }
+
+    #[test]
+    fn keeps_parameters_monomorphic_inside_their_closure() {
+        assert!(matches!(
+            run("f = x >> y >> x\ng = f 1\ncat (g 1) \"\"")
+                .unwrap_err()
+                .as_slice(),
+            [Error::UnexpectedType(_, _, _)]
+        ));
+    }
+
+    #[test]
+    fn rejects_annotations_that_are_not_generic() {
+        for source in ["bad: a -> a = x >> add x 1", "bad = x: a >> add x 1"] {
+            match infer(source).unwrap_err().as_slice() {
+                [Error::NotGeneric(_, name, r#type)] => {
+                    assert_eq!(name, "a");
+                    assert_eq!(**r#type, Type::Number);
+                }
+                errors => panic!("Expected a generic error, got {:?}", errors),
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use super::value::Value;
+
+#[derive(Clone, Default)]
+pub struct List(Option<Arc<Cell>>);
+
+struct Cell {
+    head: Value,
+    tail: List,
+}
+
+pub struct Iter<'a>(&'a List);
+
+impl List {
+    pub fn new() -> List {
+        List(None)
+    }
+
+    pub fn cons(head: Value, tail: List) -> List {
+        List(Some(Arc::new(Cell { head, tail })))
+    }
+
+    pub fn head(&self) -> Option<&Value> {
+        self.0.as_ref().map(|cell| &cell.head)
+    }
+
+    pub fn tail(&self) -> Option<List> {
+        self.0.as_ref().map(|cell| cell.tail.clone())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter(self)
+    }
+
+    pub fn reverse(&self) -> List {
+        self.iter()
+            .fold(List::new(), |list, value| List::cons(value.clone(), list))
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Value;
+
+    fn next(&mut self) -> Option<&'a Value> {
+        let cell = self.0 .0.as_ref()?;
+        self.0 = &cell.tail;
+        Some(&cell.head)
+    }
+}
+
+impl From<Vec<Value>> for List {
+    fn from(values: Vec<Value>) -> List {
+        values
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, value| List::cons(value, list))
+    }
+}
+
+impl PartialEq for List {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other.iter()).all(|(a, b)| a == b)
+    }
+}
+
+impl Drop for List {
+    fn drop(&mut self) {
+        let mut next = self.0.take();
+
+        while let Some(cell) = next {
+            next = match Arc::try_unwrap(cell) {
+                Ok(mut cell) => cell.tail.0.take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::number::Number;
+
+    fn numbers(values: &[i64]) -> List {
+        List::from(
+            values
+                .iter()
+                .map(|value| Value::Number(Number::from(*value)))
+                .collect::<Vec<Value>>(),
+        )
+    }
+
+    #[test]
+    fn shares_tails_between_lists() {
+        let tail = numbers(&[2, 3]);
+        let list = List::cons(Value::Number(Number::from(1)), tail.clone());
+
+        assert!(list == numbers(&[1, 2, 3]));
+        assert!(list.tail() == Some(tail.clone()));
+        assert_eq!(tail.len(), 2);
+    }
+
+    #[test]
+    fn reads_heads_and_tails() {
+        let list = numbers(&[1, 2]);
+
+        assert_eq!(list.head(), Some(&Value::Number(Number::from(1))));
+        assert!(list.reverse() == numbers(&[2, 1]));
+        assert!(List::new().head().is_none());
+        assert!(List::new().tail().is_none());
+        assert!(List::new().is_empty());
+    }
+
+    #[test]
+    fn drops_long_lists_without_recursing() {
+        let list = (0..200_000).fold(List::new(), |list, value| {
+            List::cons(Value::Number(Number::from(value)), list)
+        });
+
+        assert_eq!(list.len(), 200_000);
+        drop(list);
+    }
+}
@@ -1,3 +1,8 @@
+pub mod bigint;
+pub mod exhaustiveness;
+pub mod inference;
+pub mod list;
+pub mod number;
 pub mod scope;
 pub mod r#type;
 pub mod value;
@@ -12,17 +17,33 @@ use crate::syntax::*;
 use std::ops::Range;
 
 pub struct Interpreter {
-    source: String,
+    source: Vec<char>,
     pub chain: Vec<Scope>,
     pub errors: Vec<Error>,
+    pub substitution: Vec<Option<Type>>,
+    pub accesses: Vec<(Type, String, Type, Range<usize>)>,
+    pub comparisons: Vec<(Type, Range<usize>)>,
+    pub undefined: Vec<(ScopeIndex, String, usize)>,
+    pub returns: Vec<Type>,
+}
+
+impl Default for Interpreter {
+    fn default() -> Self {
+        Interpreter::new()
+    }
 }
 
 impl Interpreter {
     pub fn new() -> Interpreter {
         Interpreter {
-            source: String::new(),
+            source: vec![],
             chain: vec![Scope::global()],
             errors: vec![],
+            substitution: vec![],
+            accesses: vec![],
+            comparisons: vec![],
+            undefined: vec![],
+            returns: vec![],
         }
     }
 
@@ -30,7 +51,7 @@ impl Interpreter {
         &mut self.chain[scope].type_definition_map
     }
 
-    fn type_map(&mut self, scope: ScopeIndex) -> &mut Map<Type> {
+    pub fn type_map(&mut self, scope: ScopeIndex) -> &mut Map<Type> {
         &mut self.chain[scope].type_map
     }
 
@@ -43,7 +64,9 @@ impl Interpreter {
     }
 
     pub fn source(&self, node: Node) -> String {
-        String::from(&self.source[node.1.position..node.1.position + node.1.length])
+        let start = node.1.position.min(self.source.len());
+        let end = (node.1.position + node.1.length).min(self.source.len());
+        self.source[start..end].iter().collect()
     }
 
     pub fn range(&self, node: Node) -> Range<usize> {
@@ -51,7 +74,7 @@ impl Interpreter {
     }
 
     pub fn set_source(&mut self, source: &str) {
-        self.source = String::from(source)
+        self.source = source.chars().collect()
     }
 
     pub fn flush_errors(&mut self) -> Vec<Error> {
@@ -65,13 +88,15 @@ impl Interpreter {
         self.type_map(scope).insert(source, r#type);
     }
 
+    pub fn define_type(&mut self, scope: ScopeIndex, node: Node, r#type: Type) {
+        let source = self.source(node);
+        self.type_definition_map(scope).insert(source, r#type);
+    }
+
     pub fn lookup(&mut self, scope: ScopeIndex, node: Node) -> Option<Type> {
         let parent = self.chain.get(scope).unwrap().parent;
         let source = self.source(node);
-        let type_option: Option<Type> = match self.type_map(scope).get(&source) {
-            Some(r#type) => Some(r#type.clone()),
-            None => None,
-        };
+        let type_option = self.type_map(scope).get(&source).cloned();
 
         match type_option {
             Some(r#type) => Some(r#type),
@@ -82,13 +107,43 @@ impl Interpreter {
         }
     }
 
+    pub fn lookup_scope(&self, scope: ScopeIndex, node: Node) -> Option<ScopeIndex> {
+        match self.chain[scope].type_map.contains_key(&self.source(node)) {
+            true => Some(scope),
+            false => self.chain[scope]
+                .parent
+                .and_then(|parent| self.lookup_scope(parent, node)),
+        }
+    }
+
+    pub fn lookup_comparable(&self, scope: ScopeIndex, node: Node) -> Vec<usize> {
+        match self.lookup_scope(scope, node) {
+            Some(scope) => self.chain[scope]
+                .comparable_map
+                .get(&self.source(node))
+                .cloned()
+                .unwrap_or_default(),
+            None => vec![],
+        }
+    }
+
+    pub fn is_undefined(&self, scope: ScopeIndex, node: Node) -> bool {
+        let name = self.source(node);
+        let depth = self.returns.len();
+
+        match self.lookup_scope(scope, node) {
+            Some(scope) => self
+                .undefined
+                .iter()
+                .any(|undefined| *undefined == (scope, name.clone(), depth)),
+            None => false,
+        }
+    }
+
     pub fn lookup_type_definition(&mut self, scope: ScopeIndex, node: Node) -> Option<Type> {
         let parent = self.chain.get(scope).unwrap().parent;
         let source = self.source(node);
-        let type_option: Option<Type> = match self.type_definition_map(scope).get(&source) {
-            Some(r#type) => Some(r#type.clone()),
-            None => None,
-        };
+        let type_option = self.type_definition_map(scope).get(&source).cloned();
 
         match type_option {
             Some(r#type) => Some(r#type),
@@ -99,13 +154,27 @@ impl Interpreter {
         }
     }
 
+    pub fn define_constructor(&mut self, scope: ScopeIndex, node: Node, constructor: Constructor) {
+        let source = self.source(node);
+        self.chain[scope]
+            .constructor_map
+            .insert(source, constructor);
+    }
+
+    pub fn lookup_constructor(&self, scope: ScopeIndex, name: &str) -> Option<Constructor> {
+        match self.chain[scope].constructor_map.get(name) {
+            Some(constructor) => Some(constructor.clone()),
+            None => match self.chain[scope].parent {
+                Some(parent) => self.lookup_constructor(parent, name),
+                None => None,
+            },
+        }
+    }
+
     pub fn get(&mut self, scope: ScopeIndex, node: Node) -> Option<Value> {
         let parent = self.chain.get(scope).unwrap().parent;
         let source = self.source(node);
-        let value_option = match self.map(scope).get(&source) {
-            Some(value) => Some(value.clone()),
-            None => None,
-        };
+        let value_option = self.map(scope).get(&source).cloned();
 
         match value_option {
             Some(value) => Some(value),
@@ -124,17 +193,99 @@ impl Interpreter {
             Syntax::Literal(literal) => literal.bind(),
             Syntax::Closure(closure) => closure.bind(self, scope),
             Syntax::TypeExpression(type_expression) => type_expression.bind(self, scope),
+            Syntax::Program(program) => program.bind(self, scope),
+            Syntax::Conditional(conditional) => conditional.bind(self, scope),
+            Syntax::TypeDefinition(type_definition) => type_definition.bind(self, scope),
+            Syntax::Match(r#match) => r#match.bind(self, scope),
+            Syntax::Record(record) => record.bind(self, scope),
+            Syntax::Access(access) => access.bind(self, scope),
+            Syntax::Tuple(tuple) => tuple.bind(self, scope),
+            Syntax::List(list) => list.bind(self, scope),
+            Syntax::Binary(binary) => binary.bind(self, scope),
+            Syntax::Let(r#let) => r#let.bind(self, scope),
+            Syntax::Interpolation(interpolation) => interpolation.bind(self, scope),
+            Syntax::Try(r#try) => r#try.bind(self, scope),
         }
     }
 
-    pub fn eval(&mut self, syntax: Syntax, scope: ScopeIndex) -> Value {
+    pub fn eval(&mut self, syntax: Syntax, scope: ScopeIndex) -> Evaluation {
         match syntax {
             Syntax::Name(name) => name.eval(self, scope),
-            Syntax::Literal(literal) => literal.eval(self),
+            Syntax::Literal(literal) => Ok(literal.eval(self)),
             Syntax::Closure(closure) => closure.eval(self, scope),
             Syntax::Call(call) => call.eval(self, scope),
             Syntax::Assignment(assignment) => assignment.eval(self, scope),
-            Syntax::TypeExpression(_) => Value::None,
+            Syntax::TypeExpression(_) => Ok(Value::None),
+            Syntax::Program(program) => program.eval(self, scope),
+            Syntax::Conditional(conditional) => conditional.eval(self, scope),
+            Syntax::TypeDefinition(type_definition) => Ok(type_definition.eval(self, scope, &[])),
+            Syntax::Match(r#match) => r#match.eval(self, scope),
+            Syntax::Record(record) => record.eval(self, scope),
+            Syntax::Access(access) => access.eval(self, scope),
+            Syntax::Tuple(tuple) => tuple.eval(self, scope),
+            Syntax::List(list) => list.eval(self, scope),
+            Syntax::Binary(binary) => binary.eval(self, scope),
+            Syntax::Let(r#let) => r#let.eval(self, scope),
+            Syntax::Interpolation(interpolation) => interpolation.eval(self, scope),
+            Syntax::Try(r#try) => r#try.eval(self, scope),
         }
     }
 }
+
+#[cfg(test)]
+pub fn run(source: &str) -> Result<Value, Vec<Error>> {
+    let (syntax, errors) = parse(source, 0);
+    let mut interpreter = Interpreter::new();
+    interpreter.set_source(source);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    interpreter.bind(syntax.clone(), 0);
+    match interpreter.flush_errors() {
+        errors if errors.is_empty() => interpreter.eval(syntax, 0).map_err(|error| vec![error]),
+        errors => Err(errors),
+    }
+}
+
+#[cfg(test)]
+pub fn infer(source: &str) -> Result<Type, Vec<Error>> {
+    let (syntax, errors) = parse(source, 0);
+    let mut interpreter = Interpreter::new();
+    interpreter.set_source(source);
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let r#type = interpreter.bind(syntax, 0);
+    match interpreter.flush_errors() {
+        errors if errors.is_empty() => Ok(interpreter.resolve(r#type)),
+        errors => Err(errors),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{number::Number, run, value::Value, Interpreter};
+    use crate::syntax::{Description, Token};
+
+    #[test]
+    fn slices_names_by_character() {
+        let mut interpreter = Interpreter::new();
+        interpreter.set_source("é = ü");
+        let node = |position, length| (Token::Identifier, Description { position, length });
+
+        assert_eq!(interpreter.source(node(0, 1)), "é");
+        assert_eq!(interpreter.source(node(4, 1)), "ü");
+        assert_eq!(interpreter.source(node(5, 0)), "");
+    }
+
+    #[test]
+    fn looks_up_names_after_long_multibyte_comments() {
+        let source = format!(
+            "# {}\ncount: Number -> Number = n >> if n == 0 then 0 else count (n - 1)\ncount 100",
+            "é".repeat(20_000)
+        );
+        assert_eq!(run(&source).unwrap(), Value::Number(Number::from(0)));
+    }
+}
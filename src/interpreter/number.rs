@@ -0,0 +1,264 @@
+use std::cmp::Ordering;
+use std::fmt::*;
+use std::ops::{Add, Mul, Sub};
+
+use crate::error::Error;
+
+use super::bigint::BigInt;
+
+#[derive(Clone)]
+pub enum Number {
+    Int(BigInt),
+    Float(f64),
+}
+
+impl Number {
+    pub fn parse(source: &str) -> Number {
+        match source.contains('.') {
+            true => Number::Float(source.parse::<f64>().unwrap_or(f64::NAN)),
+            false => Number::Int(BigInt::parse(source)),
+        }
+    }
+
+    pub fn as_float(&self) -> f64 {
+        match self {
+            Number::Int(int) => int.to_f64(),
+            Number::Float(float) => *float,
+        }
+    }
+
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Number::Int(int) => int.to_i64(),
+            Number::Float(float) => Some(*float as i64),
+        }
+    }
+
+    pub fn is_integer(&self) -> bool {
+        match self {
+            Number::Int(_) => true,
+            Number::Float(float) => float.fract() == 0.0,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        match self {
+            Number::Int(int) => int.is_zero(),
+            Number::Float(float) => *float == 0.0,
+        }
+    }
+
+    fn division_by_zero() -> Error {
+        Error::RuntimeError(0..0, String::from("Division by zero"))
+    }
+
+    pub fn checked_div(self, other: Number) -> std::result::Result<Number, Error> {
+        if other.is_zero() {
+            return Err(Number::division_by_zero());
+        }
+
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) => Ok(Number::Int(int1.div_rem_euclid(&int2).0)),
+            (number1, number2) => Ok(Number::Float(number1.as_float() / number2.as_float())),
+        }
+    }
+
+    pub fn div_euclid(self, other: Number) -> std::result::Result<Number, Error> {
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) => {
+                Number::Int(int1).checked_div(Number::Int(int2))
+            }
+            (_, number2) if number2.is_zero() => Err(Number::division_by_zero()),
+            (number1, number2) => Ok(Number::Float(
+                number1.as_float().div_euclid(number2.as_float()),
+            )),
+        }
+    }
+
+    pub fn checked_rem(self, other: Number) -> std::result::Result<Number, Error> {
+        if other.is_zero() {
+            return Err(Number::division_by_zero());
+        }
+
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) => Ok(Number::Int(int1.div_rem_euclid(&int2).1)),
+            (number1, number2) => Ok(Number::Float(
+                number1.as_float().rem_euclid(number2.as_float()),
+            )),
+        }
+    }
+
+    pub fn pow(self, other: Number) -> std::result::Result<Number, Error> {
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) if !int2.is_negative() => {
+                match int2.to_i64().and_then(|int| u32::try_from(int).ok()) {
+                    Some(exponent) => Ok(Number::Int(int1.pow(exponent))),
+                    None => Err(Error::RuntimeError(
+                        0..0,
+                        String::from("Exponent is too large"),
+                    )),
+                }
+            }
+            (number1, number2) => Ok(Number::Float(number1.as_float().powf(number2.as_float()))),
+        }
+    }
+}
+
+impl From<i64> for Number {
+    fn from(int: i64) -> Number {
+        Number::Int(BigInt::from(int))
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) => int1 == int2,
+            (number1, number2) => number1.as_float() == number2.as_float(),
+        }
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) => int1.partial_cmp(int2),
+            (number1, number2) => number1.as_float().partial_cmp(&number2.as_float()),
+        }
+    }
+}
+
+impl Add for Number {
+    type Output = Number;
+
+    fn add(self, other: Number) -> Number {
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) => Number::Int(int1.add(&int2)),
+            (number1, number2) => Number::Float(number1.as_float() + number2.as_float()),
+        }
+    }
+}
+
+impl Sub for Number {
+    type Output = Number;
+
+    fn sub(self, other: Number) -> Number {
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) => Number::Int(int1.sub(&int2)),
+            (number1, number2) => Number::Float(number1.as_float() - number2.as_float()),
+        }
+    }
+}
+
+impl Mul for Number {
+    type Output = Number;
+
+    fn mul(self, other: Number) -> Number {
+        match (self, other) {
+            (Number::Int(int1), Number::Int(int2)) => Number::Int(int1.mul(&int2)),
+            (number1, number2) => Number::Float(number1.as_float() * number2.as_float()),
+        }
+    }
+}
+
+impl Debug for Number {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Number::Int(int) => write!(f, "{:?}", int),
+            Number::Float(float) => write!(f, "{:?}", float),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interpreter::run;
+
+    #[test]
+    fn parses_integers_and_floats() {
+        assert!(matches!(Number::parse("42"), Number::Int(_)));
+        assert!(matches!(Number::parse("1.5"), Number::Float(_)));
+        assert_eq!(Number::parse("-7"), Number::from(-7));
+        assert_eq!(Number::parse("2.0"), Number::from(2));
+    }
+
+    #[test]
+    fn mixes_integers_and_floats() {
+        assert_eq!(Number::from(1) + Number::Float(1.5), Number::Float(2.5));
+        assert_eq!(Number::from(2) - Number::from(3), Number::from(-1));
+        assert!(matches!(
+            Number::Float(2.0) * Number::from(3),
+            Number::Float(_)
+        ));
+        assert!(Number::from(-1) < Number::Float(0.5));
+    }
+
+    #[test]
+    fn divides_with_euclidean_remainders() {
+        assert_eq!(
+            Number::from(7).checked_div(Number::from(2)).unwrap(),
+            Number::from(3)
+        );
+        assert_eq!(
+            Number::from(-7).checked_div(Number::from(2)).unwrap(),
+            Number::from(-4)
+        );
+        assert_eq!(
+            Number::from(-7).checked_rem(Number::from(2)).unwrap(),
+            Number::from(1)
+        );
+        assert_eq!(
+            Number::Float(7.0).checked_div(Number::from(2)).unwrap(),
+            Number::Float(3.5)
+        );
+    }
+
+    #[test]
+    fn reads_negative_literals() {
+        assert_eq!(run("3 - -1").unwrap(), run("4").unwrap());
+        assert_eq!(run("add -3 1").unwrap(), run("-2").unwrap());
+        assert_eq!(run("[-1, 2]").unwrap(), run("[0 - 1, 2]").unwrap());
+        assert_eq!(
+            run("match -1 | -1 >> \"neg\" | _ >> \"other\"").unwrap(),
+            run("\"neg\"").unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_division_by_zero() {
+        assert!(Number::from(1).checked_div(Number::from(0)).is_err());
+        assert!(Number::Float(1.5).checked_div(Number::Float(0.0)).is_err());
+        assert!(Number::from(1).checked_rem(Number::from(0)).is_err());
+        assert!(Number::from(1).div_euclid(Number::from(0)).is_err());
+    }
+
+    #[test]
+    fn locates_runtime_errors_at_the_failing_call() {
+        for (source, location) in [
+            ("x = 1\ndiv x 0", 6..13),
+            ("x = 1\nx / 0", 8..9),
+            ("f = x >> div 10 x\nmap f [1, 0]", 9..17),
+        ] {
+            match run(source).unwrap_err().as_slice() {
+                [Error::RuntimeError(range, message)] => {
+                    assert_eq!(range.clone(), location);
+                    assert_eq!(message, "Division by zero");
+                }
+                errors => panic!("Expected a runtime error, got {:?}", errors),
+            }
+        }
+    }
+
+    #[test]
+    fn reads_integer_literals_beyond_64_bits() {
+        assert_eq!(
+            format!("{:?}", Number::parse("123456789012345678901234567890")),
+            "123456789012345678901234567890"
+        );
+        assert!(matches!(
+            run("pow 2 (pow 10 10)").unwrap_err().as_slice(),
+            [Error::RuntimeError(_, _)]
+        ));
+    }
+}
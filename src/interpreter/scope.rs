@@ -9,16 +9,29 @@ pub struct Scope {
     pub map: Map<Value>,
     pub type_map: Map<Type>,
     pub type_definition_map: Map<Type>,
+    pub constructor_map: Map<Constructor>,
+    pub comparable_map: Map<Vec<usize>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Constructor {
+    pub data: String,
+    pub r#type: Type,
+    pub arity: usize,
+    pub siblings: Vec<String>,
 }
 
 impl Scope {
     pub fn global() -> Scope {
         let core = core::build();
         let primitives = core::primitives();
+        let data = core::data();
 
         let mut type_definition_map = HashMap::new();
         let mut type_map = HashMap::new();
         let mut map = HashMap::new();
+        let mut constructor_map = HashMap::new();
+        let mut comparable_map = HashMap::new();
 
         for (name, r#type) in primitives {
             type_definition_map.insert(name.clone(), r#type);
@@ -26,14 +39,49 @@ impl Scope {
 
         for module in core {
             type_map.insert(module.name.clone(), module.r#type);
+            comparable_map.insert(module.name.clone(), module.comparable);
             map.insert(module.name, module.value);
         }
 
+        for data_type in data {
+            let arguments = (0..data_type.arity).map(Type::Generic).collect();
+            let data = Type::Data(data_type.name.clone(), arguments);
+            let siblings: Vec<String> = data_type
+                .variants
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect();
+            type_definition_map.insert(data_type.name.clone(), data.clone());
+
+            for (index, (name, fields)) in data_type.variants.into_iter().enumerate() {
+                let r#type = fields.iter().rev().fold(data.clone(), |r#type, field| {
+                    Type::Closure(Box::new(field.clone()), Box::new(r#type))
+                });
+
+                type_map.insert(name.clone(), r#type.clone());
+                map.insert(
+                    name.clone(),
+                    TypeDefinitionSyntax::construct(name.clone(), index, fields.len(), vec![]),
+                );
+                constructor_map.insert(
+                    name,
+                    Constructor {
+                        data: data_type.name.clone(),
+                        r#type,
+                        arity: fields.len(),
+                        siblings: siblings.clone(),
+                    },
+                );
+            }
+        }
+
         Scope {
             parent: None,
             type_definition_map,
             type_map,
             map,
+            constructor_map,
+            comparable_map,
         }
     }
 
@@ -43,6 +91,8 @@ impl Scope {
             type_map: HashMap::new(),
             type_definition_map: HashMap::new(),
             map: HashMap::new(),
+            constructor_map: HashMap::new(),
+            comparable_map: HashMap::new(),
         }
     }
 }
@@ -1,8 +1,200 @@
-#[derive(Debug, PartialEq, Clone)]
+use std::fmt::*;
+
+#[derive(PartialEq, Clone)]
 pub enum Type {
     Number,
     String,
+    Char,
     Boolean,
     Closure(Box<Type>, Box<Type>),
-    None,
+    Data(String, Vec<Type>),
+    Record(Vec<(String, Type)>),
+    Tuple(Vec<Type>),
+    Variable(usize),
+    Generic(usize),
+    Unit,
+    Error,
+}
+
+impl Type {
+    pub fn list(element: Type) -> Type {
+        Type::Data(String::from("List"), vec![element])
+    }
+
+    pub fn option(element: Type) -> Type {
+        Type::Data(String::from("Option"), vec![element])
+    }
+
+    pub fn result(error: Type, element: Type) -> Type {
+        Type::Data(String::from("Result"), vec![error, element])
+    }
+
+    pub fn map(self, mut f: impl FnMut(Type) -> Type) -> Type {
+        match self {
+            Type::Closure(param, r#return) => {
+                Type::Closure(Box::new(f(*param)), Box::new(f(*r#return)))
+            }
+            Type::Data(name, arguments) => Type::Data(name, arguments.into_iter().map(f).collect()),
+            Type::Tuple(elements) => Type::Tuple(elements.into_iter().map(f).collect()),
+            Type::Record(fields) => Type::Record(
+                fields
+                    .into_iter()
+                    .map(|(name, r#type)| (name, f(r#type)))
+                    .collect(),
+            ),
+            r#type => r#type,
+        }
+    }
+
+    pub fn children(&self) -> Vec<&Type> {
+        match self {
+            Type::Closure(param, r#return) => vec![param, r#return],
+            Type::Data(_, arguments) => arguments.iter().collect(),
+            Type::Record(fields) => fields.iter().map(|(_, r#type)| r#type).collect(),
+            Type::Tuple(elements) => elements.iter().collect(),
+            _ => vec![],
+        }
+    }
+
+    pub fn apply(self, arguments: &[Type]) -> Type {
+        match self {
+            Type::Generic(id) => match arguments.get(id) {
+                Some(argument) => argument.clone(),
+                None => Type::Generic(id),
+            },
+            r#type => r#type.map(|child| child.apply(arguments)),
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        match self {
+            Type::Generic(id) => id + 1,
+            r#type => r#type
+                .children()
+                .into_iter()
+                .map(|child| child.arity())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+}
+
+impl Debug for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Type::Number => write!(f, "Number"),
+            Type::String => write!(f, "String"),
+            Type::Char => write!(f, "Char"),
+            Type::Boolean => write!(f, "Boolean"),
+            Type::Closure(param, r#return) => match **param {
+                Type::Closure(_, _) => write!(f, "({:?}) -> {:?}", param, r#return),
+                _ => write!(f, "{:?} -> {:?}", param, r#return),
+            },
+            Type::Data(name, arguments) => {
+                write!(f, "{}", name)?;
+                for argument in arguments {
+                    match argument {
+                        Type::Closure(_, _) => write!(f, " ({:?})", argument)?,
+                        Type::Data(_, arguments) if !arguments.is_empty() => {
+                            write!(f, " ({:?})", argument)?
+                        }
+                        _ => write!(f, " {:?}", argument)?,
+                    }
+                }
+                Ok(())
+            }
+            Type::Record(fields) if fields.is_empty() => write!(f, "{{}}"),
+            Type::Record(fields) => {
+                write!(f, "{{ ")?;
+                for (index, (name, r#type)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {:?}", name, r#type)?;
+                }
+                write!(f, " }}")
+            }
+            Type::Tuple(elements) => {
+                write!(f, "(")?;
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}", element)?;
+                }
+                write!(f, ")")
+            }
+            Type::Variable(id) => write!(f, "t{}", id),
+            Type::Generic(id) => write!(f, "{}", (b'a' + *id as u8 % 26) as char),
+            Type::Unit => write!(f, "Unit"),
+            Type::Error => write!(f, "?"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::Error, interpreter::infer};
+
+    fn errors(source: &str) -> Vec<Error> {
+        infer(source).unwrap_err()
+    }
+
+    #[test]
+    fn gives_statements_and_none_the_unit_type() {
+        assert_eq!(infer("none").unwrap(), Type::Unit);
+        assert_eq!(infer("x = 1").unwrap(), Type::Unit);
+        assert_eq!(infer("u: Unit = none\nu").unwrap(), Type::Unit);
+        assert_eq!(
+            infer("f: Number -> Unit = n >> none\nf").unwrap(),
+            Type::Closure(Box::new(Type::Number), Box::new(Type::Unit))
+        );
+    }
+
+    #[test]
+    fn reports_unknown_types_in_annotations() {
+        match errors("x: Foo = 1").as_slice() {
+            [Error::UnknownType(range, name)] => {
+                assert_eq!(range.clone(), 3..6);
+                assert_eq!(name, "Foo");
+            }
+            errors => panic!("Expected an unknown type, got {:?}", errors),
+        }
+        assert!(matches!(
+            errors("f: Number -> Strng = n >> \"a\"").as_slice(),
+            [Error::UnknownType(_, _)]
+        ));
+    }
+
+    #[test]
+    fn suppresses_errors_that_follow_from_an_earlier_error() {
+        assert!(matches!(
+            errors("x = nope\ny = x + 1\nz = cat x \"a\"\nx.field").as_slice(),
+            [Error::UnknownName(_, _)]
+        ));
+        assert!(matches!(errors("(1 2) 3").as_slice(), [Error::BadCall(_)]));
+        assert!(matches!(
+            errors("r = {a = 1}\n(r.b) + 1").as_slice(),
+            [Error::UnknownField(_, _, _)]
+        ));
+        assert!(matches!(
+            errors("match nope | (a, b) >> a").as_slice(),
+            [Error::UnknownName(_, _)]
+        ));
+        assert!(matches!(
+            errors("type T = T (List Foo)").as_slice(),
+            [Error::UnknownType(_, _)]
+        ));
+    }
+
+    #[test]
+    fn substitutes_generic_arguments() {
+        assert_eq!(
+            Type::result(Type::Generic(0), Type::Generic(1)).apply(&[Type::String, Type::Number]),
+            Type::result(Type::String, Type::Number)
+        );
+        assert_eq!(Type::option(Type::Generic(1)).arity(), 2);
+        assert_eq!(Type::list(Type::Number).arity(), 0);
+    }
 }
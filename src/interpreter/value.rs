@@ -1,21 +1,55 @@
+use std::cmp::Ordering;
 use std::fmt::*;
 use std::sync::Arc;
 
+use super::list::List;
+use super::number::Number;
 use super::*;
 use crate::editor::escape_code::*;
+use crate::error::Error;
 
 pub enum Value {
-    Number(usize),
+    Number(Number),
     String(String),
+    Char(char),
     Boolean(bool),
-    Closure(Arc<dyn Fn(Value, &mut Interpreter) -> Value>),
+    Closure(Function),
+    Lazy(LazyFunction),
+    Variant(String, usize, Vec<Value>),
+    Record(Vec<(String, Value)>),
+    Tuple(Vec<Value>),
+    List(List),
     None,
 }
 
+pub type Function = Arc<dyn Fn(Value, &mut Interpreter) -> Evaluation + Send + Sync>;
+
+pub type LazyFunction = Arc<dyn Fn(Thunk, &mut Interpreter) -> Evaluation + Send + Sync>;
+
+pub type Thunk = Arc<dyn Fn(&mut Interpreter) -> Evaluation + Send + Sync>;
+
+pub type Evaluation = std::result::Result<Value, Error>;
+
 impl Value {
-    pub fn unwrap_number(self) -> usize {
+    pub fn some(value: Value) -> Value {
+        Value::Variant(String::from("Some"), 0, vec![value])
+    }
+
+    pub fn none() -> Value {
+        Value::Variant(String::from("None"), 1, vec![])
+    }
+
+    pub fn ok(value: Value) -> Value {
+        Value::Variant(String::from("Ok"), 0, vec![value])
+    }
+
+    pub fn err(value: Value) -> Value {
+        Value::Variant(String::from("Err"), 1, vec![value])
+    }
+
+    pub fn unwrap_number(self) -> Number {
         match self {
-            Value::Number(usize) => usize,
+            Value::Number(number) => number,
             _ => panic!(),
         }
     }
@@ -27,6 +61,13 @@ impl Value {
         }
     }
 
+    pub fn unwrap_char(self) -> char {
+        match self {
+            Value::Char(char) => char,
+            _ => panic!(),
+        }
+    }
+
     pub fn unwrap_bool(self) -> bool {
         match self {
             Value::Boolean(bool) => bool,
@@ -34,21 +75,86 @@ impl Value {
         }
     }
 
-    pub fn unwrap_closure(self) -> Arc<dyn Fn(Value, &mut Interpreter) -> Value> {
+    pub fn unwrap_closure(self) -> Function {
         match self {
             Value::Closure(func) => func,
             _ => panic!(),
         }
     }
+
+    pub fn unwrap_variant(self) -> (String, Vec<Value>) {
+        match self {
+            Value::Variant(name, _, fields) => (name, fields),
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_record(self) -> Vec<(String, Value)> {
+        match self {
+            Value::Record(fields) => fields,
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_tuple(self) -> Vec<Value> {
+        match self {
+            Value::Tuple(elements) => elements,
+            _ => panic!(),
+        }
+    }
+
+    pub fn unwrap_list(self) -> List {
+        match self {
+            Value::List(list) => list,
+            _ => panic!(),
+        }
+    }
+
+    pub fn comparable(self) -> Evaluation {
+        let is_comparable = match &self {
+            Value::Closure(_) | Value::Lazy(_) => false,
+            Value::Variant(_, _, values) | Value::Tuple(values) => values
+                .iter()
+                .all(|value| value.clone().comparable().is_ok()),
+            Value::Record(fields) => fields
+                .iter()
+                .all(|(_, value)| value.clone().comparable().is_ok()),
+            Value::List(list) => list.iter().all(|value| value.clone().comparable().is_ok()),
+            _ => true,
+        };
+
+        match is_comparable {
+            true => Ok(self),
+            false => Err(Error::RuntimeError(
+                0..0,
+                String::from("Cannot compare closures"),
+            )),
+        }
+    }
+
+    pub fn apply(self, argument: Value, interpreter: &mut Interpreter) -> Evaluation {
+        match self {
+            Value::Lazy(func) => func(Arc::new(move |_| Ok(argument.clone())), interpreter),
+            value => value.unwrap_closure()(argument, interpreter),
+        }
+    }
 }
 
 impl Clone for Value {
     fn clone(&self) -> Self {
         match self {
-            Value::Number(usize) => Value::Number(usize.clone()),
+            Value::Number(number) => Value::Number(number.clone()),
             Value::String(string) => Value::String(string.clone()),
-            Value::Boolean(bool) => Value::Boolean(bool.clone()),
+            Value::Char(char) => Value::Char(*char),
+            Value::Boolean(bool) => Value::Boolean(*bool),
             Value::Closure(func) => Value::Closure(func.clone()),
+            Value::Lazy(func) => Value::Lazy(func.clone()),
+            Value::Variant(name, index, fields) => {
+                Value::Variant(name.clone(), *index, fields.clone())
+            }
+            Value::Record(fields) => Value::Record(fields.clone()),
+            Value::Tuple(elements) => Value::Tuple(elements.clone()),
+            Value::List(list) => Value::List(list.clone()),
             Value::None => Value::None,
         }
     }
@@ -57,10 +163,54 @@ impl Clone for Value {
 impl Debug for Value {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
-            Value::Number(usize) => write!(f, "{}{}{}", YELLOW, usize, RESET),
+            Value::Number(number) => write!(f, "{}{:?}{}", YELLOW, number, RESET),
             Value::String(string) => write!(f, "{}\"{}\"{}", GREEN, string, RESET),
+            Value::Char(char) => write!(f, "{}'{}'{}", GREEN, char, RESET),
             Value::Boolean(bool) => write!(f, "{}{}{}", CYAN, bool, RESET),
-            Value::Closure(_) => write!(f, "{}[Closure]{}", MAGENTA, RESET),
+            Value::Closure(_) | Value::Lazy(_) => write!(f, "{}[Closure]{}", MAGENTA, RESET),
+            Value::Variant(name, _, fields) => {
+                write!(f, "{}{}{}", BLUE, name, RESET)?;
+                for field in fields {
+                    match field {
+                        Value::Variant(_, _, inner) if !inner.is_empty() => {
+                            write!(f, " ({:?})", field)?
+                        }
+                        _ => write!(f, " {:?}", field)?,
+                    }
+                }
+                Ok(())
+            }
+            Value::Record(fields) if fields.is_empty() => write!(f, "{{}}"),
+            Value::Record(fields) => {
+                write!(f, "{{ ")?;
+                for (index, (name, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} = {:?}", name, value)?;
+                }
+                write!(f, " }}")
+            }
+            Value::Tuple(elements) => {
+                write!(f, "(")?;
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}", element)?;
+                }
+                write!(f, ")")
+            }
+            Value::List(list) => {
+                write!(f, "[")?;
+                for (index, element) in list.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{:?}", element)?;
+                }
+                write!(f, "]")
+            }
             Value::None => write!(f, "{}[None]{}", MAGENTA, RESET),
         }
     }
@@ -70,18 +220,132 @@ impl PartialEq for Value {
     fn eq(&self, other: &Self) -> bool {
         let other = other.clone();
         match self {
-            Value::Number(usize) => usize == &other.unwrap_number(),
+            Value::Number(number) => number == &other.unwrap_number(),
             Value::String(string) => string == &other.unwrap_string(),
+            Value::Char(char) => char == &other.unwrap_char(),
             Value::Boolean(bool) => bool == &other.unwrap_bool(),
-            Value::Closure(_) => false,
-            Value::None => match other {
-                Value::None => true,
-                _ => false,
+            Value::Closure(_) | Value::Lazy(_) => false,
+            Value::Variant(name, _, fields) => {
+                let (other_name, other_fields) = other.unwrap_variant();
+                name == &other_name && fields == &other_fields
+            }
+            Value::Record(fields) => fields == &other.unwrap_record(),
+            Value::Tuple(elements) => elements == &other.unwrap_tuple(),
+            Value::List(list) => list == &other.unwrap_list(),
+            Value::None => matches!(other, Value::None),
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let other = other.clone();
+        match self {
+            Value::Number(number) => number.partial_cmp(&other.unwrap_number()),
+            Value::String(string) => string.partial_cmp(&other.unwrap_string()),
+            Value::Char(char) => char.partial_cmp(&other.unwrap_char()),
+            Value::Boolean(bool) => bool.partial_cmp(&other.unwrap_bool()),
+            Value::Closure(_) | Value::Lazy(_) => None,
+            Value::Variant(_, index, fields) => match other {
+                Value::Variant(_, other_index, other_fields) => index
+                    .partial_cmp(&other_index)
+                    .filter(|ordering| ordering.is_ne())
+                    .or_else(|| fields.partial_cmp(&other_fields)),
+                _ => None,
             },
+            Value::Record(fields) => fields.partial_cmp(&other.unwrap_record()),
+            Value::Tuple(elements) => elements.partial_cmp(&other.unwrap_tuple()),
+            Value::List(list) => list.iter().partial_cmp(other.unwrap_list().iter()),
+            Value::None => Some(Ordering::Equal),
         }
     }
 }
 
 pub fn inner_string(string: String) -> String {
-    String::from(&string[1..string.len() - 1])
+    let (raw, string) = match string.strip_prefix('r') {
+        Some(string) => (true, string),
+        None => (false, string.as_str()),
+    };
+    let delimiter = match string.starts_with("\"\"\"") {
+        true => "\"\"\"",
+        false => "\"",
+    };
+    let string = string.strip_prefix(delimiter).unwrap_or(string);
+    let string = string.strip_suffix(delimiter).unwrap_or(string);
+
+    match raw {
+        true => String::from(string),
+        false => unescape(string),
+    }
+}
+
+pub fn inner_char(char: String) -> char {
+    let char = char.strip_prefix('\'').unwrap_or(&char);
+    let char = char.strip_suffix('\'').unwrap_or(char);
+
+    unescape(char).chars().next().unwrap_or('\0')
+}
+
+pub fn unescape(string: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = string.chars();
+
+    while let Some(char) = chars.next() {
+        if char != '\\' {
+            unescaped.push(char);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('0') => unescaped.push('\0'),
+            Some('u') => {
+                let code: String = chars
+                    .by_ref()
+                    .skip(1)
+                    .take_while(|char| *char != '}')
+                    .collect();
+                if let Some(char) = u32::from_str_radix(&code, 16).ok().and_then(char::from_u32) {
+                    unescaped.push(char)
+                }
+            }
+            Some(char) => unescaped.push(char),
+            None => (),
+        }
+    }
+
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unescapes_escape_sequences() {
+        assert_eq!(unescape(r"a\nb\tc\r\0"), "a\nb\tc\r\0");
+        assert_eq!(unescape(r#"say \"hi\" \\"#), "say \"hi\" \\");
+        assert_eq!(unescape(r"\u{48}\u{1F600}"), "H\u{1F600}");
+    }
+
+    #[test]
+    fn strips_string_delimiters() {
+        assert_eq!(inner_string(String::from(r#""a\nb""#)), "a\nb");
+        assert_eq!(
+            inner_string(String::from("\"\"\"one\n\"two\"\"\"\"")),
+            "one\n\"two\""
+        );
+        assert_eq!(inner_string(String::from(r#"r"C:\path\n""#)), r"C:\path\n");
+        assert_eq!(inner_string(String::from(r#""""#)), "");
+    }
+
+    #[test]
+    fn strips_character_delimiters() {
+        assert_eq!(inner_char(String::from("'a'")), 'a');
+        assert_eq!(inner_char(String::from(r"'\n'")), '\n');
+        assert_eq!(inner_char(String::from(r"'\''")), '\'');
+        assert_eq!(inner_char(String::from(r"'\u{41}'")), 'A');
+    }
 }
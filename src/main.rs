@@ -1,7 +1,3 @@
-#![feature(box_syntax)]
-#![feature(never_type)]
-#![feature(backtrace)]
-
 pub mod core;
 pub mod editor;
 pub mod error;
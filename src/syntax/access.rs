@@ -0,0 +1,106 @@
+use crate::{
+    error::Error,
+    interpreter::{r#type::Type, scope::ScopeIndex, value::Evaluation, Interpreter},
+};
+
+use super::{Branch, Leaf};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AccessSyntax {
+    pub expression: Branch,
+    pub dot: Leaf,
+    pub name: Leaf,
+}
+
+impl AccessSyntax {
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let expression = interpreter.bind(*self.expression.clone(), scope);
+        let name = interpreter.source(self.name);
+
+        let r#type = interpreter.resolve(expression);
+
+        if let Type::Record(fields) = &r#type {
+            if let Some((_, field)) = fields.iter().find(|(field, _)| field == &name) {
+                return field.clone();
+            }
+        }
+
+        if let Type::Error = r#type {
+            return Type::Error;
+        }
+
+        if let Type::Variable(_) = r#type {
+            let field = interpreter.fresh();
+            interpreter
+                .accesses
+                .push((r#type, name, field.clone(), interpreter.range(self.name)));
+            return field;
+        }
+
+        interpreter.error(Error::UnknownField(
+            interpreter.range(self.name),
+            name,
+            Box::new(r#type),
+        ));
+        Type::Error
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let name = interpreter.source(self.name);
+
+        Ok(interpreter
+            .eval(*self.expression.clone(), scope)?
+            .unwrap_record()
+            .into_iter()
+            .find(|(field, _)| field == &name)
+            .map(|(_, value)| value)
+            .unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{number::Number, run, value::Value},
+    };
+
+    #[test]
+    fn reads_record_fields() {
+        assert_eq!(
+            run("p = { name = \"a\", inner = { x = 5 } }\np.inner.x").unwrap(),
+            Value::Number(Number::from(5))
+        );
+        assert_eq!(
+            run("f = p: { a: Number } >> p.a\nf { a = 2 }").unwrap(),
+            Value::Number(Number::from(2))
+        );
+        assert_eq!(
+            run("(p >> p.a) { a = 3 }").unwrap(),
+            Value::Number(Number::from(3))
+        );
+    }
+
+    #[test]
+    fn reports_missing_fields() {
+        match run("p = { name = \"a\" }\np.age").unwrap_err().as_slice() {
+            [Error::UnknownField(range, name, _)] => {
+                assert_eq!(range.clone(), 21..24);
+                assert_eq!(name, "age");
+            }
+            errors => panic!("Expected an unknown field, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn asks_for_an_annotation_when_the_record_is_unknown() {
+        match run("f = x >> x.a").unwrap_err().as_slice() {
+            [error @ Error::AmbiguousAccess(range, name)] => {
+                assert_eq!(range.clone(), 11..12);
+                assert_eq!(name, "a");
+                assert!(!format!("{:?}", error).contains("t2"));
+            }
+            errors => panic!("Expected an ambiguous access, got {:?}", errors),
+        }
+    }
+}
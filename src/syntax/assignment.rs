@@ -1,17 +1,29 @@
-use super::{Branch, Leaf, Parser, Syntax, Token, TypeExpressionSyntax};
+use super::{Branch, Leaf, Parser, PatternSyntax, Syntax, Token, TypeExpressionSyntax};
 use crate::{
     error::Error,
-    interpreter::{r#type::Type, scope::ScopeIndex, value::Value, *},
+    interpreter::{
+        r#type::Type,
+        scope::{Scope, ScopeIndex},
+        value::{Evaluation, Value},
+        *,
+    },
 };
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct AssignmentSyntax {
     pub name: Leaf,
+    pub pattern: Option<PatternSyntax>,
     pub type_expression: Option<TypeExpressionSyntax>,
     pub equals: Leaf,
     pub expression: Branch,
 }
 
+#[derive(Debug, Clone)]
+pub struct Signature {
+    pub r#type: Type,
+    pub scope: ScopeIndex,
+}
+
 impl AssignmentSyntax {
     pub fn parse_with_type(
         name: Leaf,
@@ -20,6 +32,7 @@ impl AssignmentSyntax {
     ) -> AssignmentSyntax {
         AssignmentSyntax {
             name,
+            pattern: None,
             type_expression: Some(type_expression),
             equals: parser.expect(Token::Equals),
             expression: Box::new(Syntax::parse(parser)),
@@ -31,46 +44,191 @@ impl AssignmentSyntax {
     pub fn parse(parser: &mut Parser) -> AssignmentSyntax {
         AssignmentSyntax {
             name: parser.expect(Token::Identifier),
+            pattern: None,
             type_expression: None,
             equals: parser.assert(Token::Equals),
             expression: Box::new(Syntax::parse(parser)),
         }
     }
 
+    pub fn parse_pattern(parser: &mut Parser) -> AssignmentSyntax {
+        let pattern = PatternSyntax::parse(parser);
+
+        AssignmentSyntax {
+            name: pattern.leaf(),
+            pattern: Some(pattern),
+            type_expression: None,
+            equals: parser.expect(Token::Equals),
+            expression: Box::new(Syntax::parse(parser)),
+        }
+    }
+
     pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
-        let expression_type = interpreter.bind(*self.expression.clone(), scope);
+        if let Some(pattern) = &self.pattern {
+            let expression_type = interpreter.bind(*self.expression.clone(), scope);
+            pattern.bind(interpreter, scope, expression_type.clone());
+            if interpreter.prune(expression_type) != Type::Error {
+                pattern.check_exhaustive(interpreter, scope);
+            }
+            return Type::Unit;
+        }
 
-        if let Some(type_expression) = self.type_expression.clone() {
-            let name_type =
-                interpreter.bind(Syntax::TypeExpression(type_expression.clone()), scope);
-            if expression_type != name_type {
-                interpreter.error(Error::MismatchedTypeAssignment(
-                    interpreter.range(type_expression.name),
-                    interpreter.source(self.name),
-                    expression_type.clone(),
-                    name_type,
-                ));
-
-                return Type::None;
+        match self.signature(interpreter, scope) {
+            Some(signature) => {
+                self.declare(interpreter, scope, signature.r#type.clone());
+                self.bind_declared(interpreter, scope, signature)
+            }
+            None => {
+                let expression_type = interpreter.bind(*self.expression.clone(), scope);
+                interpreter.generalize_comparisons(scope, self.name, expression_type.clone());
+                let generalized = interpreter.generalize(scope, expression_type);
+                self.declare(interpreter, scope, generalized);
+                Type::Unit
             }
         }
+    }
 
-        if let Some(_) = interpreter.lookup(scope, self.name) {
-            interpreter.error(Error::Reassignment(
-                interpreter.range(self.name),
-                interpreter.source(self.name),
-            ))
+    pub fn signature(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Option<Signature> {
+        if self.pattern.is_some() {
+            return None;
+        }
+
+        interpreter.chain.push(Scope::new(scope));
+        let annotation_scope = interpreter.chain.len() - 1;
+
+        let r#type = match (self.type_expression.clone(), *self.expression.clone()) {
+            (Some(type_expression), _) => {
+                interpreter.bind(Syntax::TypeExpression(type_expression), annotation_scope)
+            }
+            (None, Syntax::Closure(closure)) => closure.signature(interpreter, annotation_scope),
+            (None, _) => return None,
+        };
+
+        Some(Signature {
+            r#type,
+            scope: annotation_scope,
+        })
+    }
+
+    pub fn declare(&self, interpreter: &mut Interpreter, scope: ScopeIndex, r#type: Type) {
+        let source = interpreter.source(self.name);
+        if interpreter.type_map(scope).contains_key(&source) {
+            interpreter.error(Error::Reassignment(interpreter.range(self.name), source))
         } else {
-            interpreter.declare(scope, self.name, expression_type);
+            interpreter.declare(scope, self.name, r#type);
         }
+    }
+
+    pub fn bind_declared(
+        &self,
+        interpreter: &mut Interpreter,
+        scope: ScopeIndex,
+        signature: Signature,
+    ) -> Type {
+        let expression_type = interpreter.bind(*self.expression.clone(), scope);
+
+        let range = match self.type_expression.clone() {
+            Some(type_expression) => type_expression.range(),
+            None => interpreter.range(self.name),
+        };
 
-        Type::None
+        if !interpreter.unify(signature.r#type.clone(), expression_type.clone()) {
+            let [received, expected] =
+                interpreter.readable([expression_type, signature.r#type.clone()]);
+            interpreter.error(Error::MismatchedTypeAssignment(
+                range.clone(),
+                interpreter.source(self.name),
+                Box::new(received),
+                Box::new(expected),
+            ));
+        }
+
+        interpreter.check_accesses();
+        interpreter.check_comparisons();
+
+        if self.type_expression.is_some() {
+            interpreter.check_generic(signature.scope, range);
+        }
+
+        let name = interpreter.source(self.name);
+        interpreter.type_map(scope).remove(&name);
+        interpreter.generalize_comparisons(scope, self.name, signature.r#type.clone());
+        let generalized = interpreter.generalize(scope, signature.r#type);
+        interpreter.declare(scope, self.name, generalized);
+
+        Type::Unit
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let value = interpreter.eval(*self.expression.clone(), scope)?;
+
+        match &self.pattern {
+            Some(pattern) => pattern.assign(interpreter, scope, value)?,
+            None => {
+                let name = interpreter.source(self.name);
+                interpreter.map(scope).insert(name, value);
+            }
+        }
+
+        Ok(Value::None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{number::Number, r#type::Type, run, value::Value},
+    };
+
+    #[test]
+    fn binds_recursive_definitions() {
+        assert_eq!(
+            run("fact = n: Number -> Number >> if eq n 0 then 1 else mul n (fact (dec n))\nfact 5")
+                .unwrap(),
+            Value::Number(Number::from(120))
+        );
+        assert_eq!(
+            run("fact = n >> if eq n 0 then 1 else mul n (fact (dec n))\nfact 4").unwrap(),
+            Value::Number(Number::from(24))
+        );
+    }
+
+    #[test]
+    fn binds_mutually_recursive_definitions() {
+        let source = "even = n: Number -> Boolean >> if eq n 0 then true else odd (dec n)\n\
+                      odd = n: Number -> Boolean >> if eq n 0 then false else even (dec n)\n";
+
+        assert_eq!(
+            run(&format!("{}even 10", source)).unwrap(),
+            Value::Boolean(true)
+        );
+        assert_eq!(
+            run(&format!("{}odd 10", source)).unwrap(),
+            Value::Boolean(false)
+        );
     }
 
-    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Value {
-        let name = String::from(interpreter.source(self.name));
-        let value = interpreter.eval(*self.expression.clone(), scope);
-        interpreter.map(scope).insert(name, value);
-        Value::None
+    #[test]
+    fn checks_return_type_annotations() {
+        match run("f = n: Number -> String >> n").unwrap_err().as_slice() {
+            [Error::UnexpectedType(range, expected, received)] => {
+                assert_eq!(range.clone(), 27..28);
+                assert_eq!(**expected, Type::String);
+                assert_eq!(**received, Type::Number);
+            }
+            errors => panic!("Expected a type error, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn rejects_reassignment() {
+        match run("x = 1\nx = 2").unwrap_err().as_slice() {
+            [Error::Reassignment(range, name)] => {
+                assert_eq!(range.clone(), 6..7);
+                assert_eq!(name, "x");
+            }
+            errors => panic!("Expected a reassignment, got {:?}", errors),
+        }
     }
 }
@@ -0,0 +1,148 @@
+use crate::interpreter::{r#type::Type, scope::ScopeIndex, value::Evaluation, Interpreter};
+
+use super::{Branch, CallSyntax, Leaf, Parser, Syntax, Token};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BinarySyntax {
+    pub left: Branch,
+    pub operator: Leaf,
+    pub right: Branch,
+}
+
+impl BinarySyntax {
+    pub fn parse(parser: &mut Parser) -> Syntax {
+        BinarySyntax::climb(parser, 0)
+    }
+
+    fn climb(parser: &mut Parser, minimum: usize) -> Syntax {
+        let mut left = CallSyntax::parse(parser);
+
+        parser.skip_newlines_before(Token::Pipeline);
+        while let Some(precedence) = parser.current().precedence() {
+            if precedence < minimum {
+                break;
+            }
+
+            let operator = parser.advance();
+            let right = BinarySyntax::climb(parser, precedence + 1);
+            left = match operator.0 {
+                Token::Pipeline => Syntax::Call(CallSyntax(Box::new(right), Box::new(left))),
+                _ => Syntax::Binary(BinarySyntax {
+                    left: Box::new(left),
+                    operator,
+                    right: Box::new(right),
+                }),
+            };
+            parser.skip_newlines_before(Token::Pipeline);
+        }
+
+        left
+    }
+
+    fn name(&self) -> &'static str {
+        match self.operator.0 {
+            Token::Plus => "add",
+            Token::Minus => "sub",
+            Token::Asterisk => "mul",
+            Token::Slash => "div",
+            Token::DoublePlus => "cat",
+            Token::DoubleAmpersand => "and",
+            Token::DoubleBar => "or",
+            Token::Compose => "compose",
+            Token::DoubleEquals => "eq",
+            Token::LessThan => "lt",
+            Token::GreaterThan => "gt",
+            Token::LessThanEquals => "lte",
+            Token::GreaterThanEquals => "gte",
+            _ => panic!(),
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let left = interpreter.bind(*self.left.clone(), scope);
+        let right = interpreter.bind(*self.right.clone(), scope);
+        let range = interpreter.range(self.operator);
+
+        let r#type = interpreter.type_map(0)[self.name()].clone();
+
+        match interpreter.instantiate(r#type) {
+            Type::Closure(param1, r#return) => match *r#return {
+                Type::Closure(param2, r#return) => {
+                    interpreter.expect(*param1, left, range.clone());
+                    interpreter.expect(*param2, right, range);
+                    *r#return
+                }
+                _ => panic!(),
+            },
+            _ => panic!(),
+        }
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let left = interpreter.eval(*self.left.clone(), scope)?;
+        let function = interpreter.map(0)[self.name()].clone();
+
+        let value = match function.apply(left, interpreter) {
+            Ok(function) => CallSyntax::apply(interpreter, scope, function, &self.right),
+            Err(error) => Err(error),
+        };
+
+        value.map_err(|error| error.locate(interpreter.range(self.operator)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, number::Number, r#type::Type, run, value::Value},
+    };
+
+    #[test]
+    fn applies_operators_by_precedence() {
+        assert_eq!(
+            run("1 + 2 * 3 - 4").unwrap(),
+            Value::Number(Number::from(3))
+        );
+        assert_eq!(
+            run("\"a\" ++ \"b\" ++ \"c\"").unwrap(),
+            Value::String(String::from("abc"))
+        );
+        assert_eq!(run("1 < 2 && 2 <= 2").unwrap(), Value::Boolean(true));
+    }
+
+    #[test]
+    fn compares_values_of_any_type() {
+        assert_eq!(run("'a' == 'b'").unwrap(), Value::Boolean(false));
+        assert_eq!(run("[1, 2] == [1, 2]").unwrap(), Value::Boolean(true));
+        assert_eq!(run("\"a\" < \"b\"").unwrap(), Value::Boolean(true));
+        assert_eq!(run("(1, 'b') >= (1, 'a')").unwrap(), Value::Boolean(true));
+    }
+
+    #[test]
+    fn infers_generic_comparisons() {
+        match infer("a >> b >> a == b").unwrap() {
+            Type::Closure(a, r#return) => match *r#return {
+                Type::Closure(b, r#return) => {
+                    assert!(matches!(*a, Type::Variable(_)));
+                    assert_eq!(a, b);
+                    assert_eq!(*r#return, Type::Boolean);
+                }
+                r#type => panic!("Expected a closure, got {:?}", r#type),
+            },
+            r#type => panic!("Expected a closure, got {:?}", r#type),
+        }
+    }
+
+    #[test]
+    fn reports_mismatched_operands_once() {
+        match infer("1 == \"a\"").unwrap_err().as_slice() {
+            [Error::UnexpectedType(range, expected, received)] => {
+                assert_eq!(range.clone(), 2..4);
+                assert_eq!(**expected, Type::Number);
+                assert_eq!(**received, Type::String);
+            }
+            errors => panic!("Expected a single type error, got {:?}", errors),
+        }
+    }
+}
@@ -1,9 +1,17 @@
+use std::ops::Range;
+use std::sync::Arc;
+
 use crate::{
     error::Error,
-    interpreter::{r#type::Type, scope::ScopeIndex, value::Value, Interpreter},
+    interpreter::{
+        r#type::Type,
+        scope::ScopeIndex,
+        value::{Evaluation, Value},
+        Interpreter,
+    },
 };
 
-use super::{Branch, Parser, Syntax, Token};
+use super::{Branch, Parser, Syntax, Token, TrySyntax};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct CallSyntax(pub Branch, pub Branch);
@@ -12,32 +20,129 @@ impl CallSyntax {
     pub fn parse(parser: &mut Parser) -> Syntax {
         let mut left = parser.primary();
 
-        while parser.current() != Token::EndOfFile && parser.current() != Token::CloseParenthesis {
-            let right = parser.primary();
-            left = Syntax::Call(CallSyntax(Box::new(left), Box::new(right)))
+        loop {
+            left = match parser.current() {
+                Token::Question => Syntax::Try(TrySyntax {
+                    expression: Box::new(left),
+                    question: parser.advance(),
+                }),
+                token if token.is_primary() => {
+                    let right = parser.primary();
+                    Syntax::Call(CallSyntax(Box::new(left), Box::new(right)))
+                }
+                _ => break,
+            };
         }
 
         left
     }
 
+    pub fn range(&self) -> Range<usize> {
+        let (left, right) = (self.0.range(), self.1.range());
+        left.start.min(right.start)..left.end.max(right.end)
+    }
+
     pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
         let left = interpreter.bind(*self.0.clone(), scope);
         let right = interpreter.bind(*self.1.clone(), scope);
 
-        if let Type::Closure(param, r#return) = left {
-            if right != *param {
-                interpreter.error(Error::UnexpectedType(0..0, *param, right));
+        match interpreter.prune(left) {
+            Type::Closure(param, r#return) => {
+                interpreter.expect(*param, right, self.1.range());
+                *r#return
+            }
+            Type::Variable(id) => {
+                let r#return = interpreter.fresh();
+                interpreter.expect(
+                    Type::Variable(id),
+                    Type::Closure(Box::new(right), Box::new(r#return.clone())),
+                    self.0.range(),
+                );
+                r#return
+            }
+            Type::Error => Type::Error,
+            _ => {
+                interpreter.error(Error::BadCall(self.0.range()));
+                Type::Error
             }
-            *r#return
-        } else {
-            interpreter.error(Error::BadCall(0..0));
-            Type::None
         }
     }
 
-    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Value {
-        let left = interpreter.eval(*self.0.clone(), scope).unwrap_closure();
-        let right = interpreter.eval(*self.1.clone(), scope);
-        left(right, interpreter)
+    pub fn apply(
+        interpreter: &mut Interpreter,
+        scope: ScopeIndex,
+        left: Value,
+        right: &Branch,
+    ) -> Evaluation {
+        match left {
+            Value::Lazy(left) => {
+                let right = right.clone();
+                left(
+                    Arc::new(move |interpreter: &mut Interpreter| {
+                        interpreter.eval(*right.clone(), scope)
+                    }),
+                    interpreter,
+                )
+            }
+            left => {
+                let right = interpreter.eval(*right.clone(), scope)?;
+                left.unwrap_closure()(right, interpreter)
+            }
+        }
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let left = interpreter.eval(*self.0.clone(), scope)?;
+        let value = CallSyntax::apply(interpreter, scope, left, &self.1);
+
+        value.map_err(|error| error.locate(self.range()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, r#type::Type, run},
+    };
+
+    #[test]
+    fn pipes_values_into_calls() {
+        assert_eq!(
+            run("double = x >> x * 2\n3 |> inc |> double").unwrap(),
+            run("8").unwrap()
+        );
+        assert_eq!(run("\"b\" |> cat \"a\"").unwrap(), run("\"ab\"").unwrap());
+        assert_eq!(run("1 + 2 |> inc").unwrap(), run("4").unwrap());
+        assert_eq!(
+            run("[1, 2, 3]\n  |> map inc\n  |> fold add 0").unwrap(),
+            run("9").unwrap()
+        );
+    }
+
+    #[test]
+    fn composes_functions() {
+        assert_eq!(
+            run("double = x >> x * 2\nf = inc >>> double\nf 3").unwrap(),
+            run("8").unwrap()
+        );
+        assert_eq!(run("(inc >>> inc >>> inc) 0").unwrap(), run("3").unwrap());
+        assert_eq!(run("compose inc inc 1").unwrap(), run("3").unwrap());
+        assert_eq!(
+            infer("inc >>> (n >> n == 1)").unwrap(),
+            Type::Closure(Box::new(Type::Number), Box::new(Type::Boolean))
+        );
+    }
+
+    #[test]
+    fn locates_errors_in_pipelines() {
+        match run("0 |> div 1").unwrap_err().as_slice() {
+            [Error::RuntimeError(range, _)] => assert_eq!(range.clone(), 0..10),
+            errors => panic!("Expected a runtime error, got {:?}", errors),
+        }
+        match run("\"a\" |> inc").unwrap_err().as_slice() {
+            [Error::UnexpectedType(range, _, _)] => assert_eq!(range.clone(), 0..3),
+            errors => panic!("Expected a type error, got {:?}", errors),
+        }
     }
 }
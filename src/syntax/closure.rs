@@ -1,69 +1,265 @@
 use std::sync::Arc;
 
-use crate::interpreter::{
-    r#type::Type,
-    scope::{Scope, ScopeIndex},
-    value::Value,
-    Interpreter,
+use crate::{
+    error::Error,
+    interpreter::{
+        r#type::Type,
+        scope::{Scope, ScopeIndex},
+        value::{Evaluation, Function, Value},
+        Interpreter,
+    },
 };
 
-use super::{Branch, Leaf, Parser, Syntax, Token, TypeExpressionSyntax};
+use super::{Branch, Leaf, Parser, PatternSyntax, Syntax, Token, TypeExpressionSyntax};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct ClosureSyntax {
     pub name: Leaf,
-    pub type_expression: TypeExpressionSyntax,
+    pub pattern: Option<PatternSyntax>,
+    pub type_expression: Option<TypeExpressionSyntax>,
+    pub return_type_expression: Option<TypeExpressionSyntax>,
     pub lambda: Leaf,
     pub expression: Branch,
 }
 
 impl ClosureSyntax {
-    fn create_closure(
-        &self,
-        interpreter: &mut Interpreter,
-        scope: ScopeIndex,
-    ) -> Box<dyn Fn(Value, &mut Interpreter) -> Value> {
+    fn create_closure(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Function {
         let expression = self.expression.clone();
-        let name = self.name.clone();
+        let name = self.name;
+        let pattern = self.pattern.clone();
         let source = interpreter.source(name);
 
-        box move |value: Value, interpreter: &mut Interpreter| {
-            let mut scope = Scope::new(scope);
-            scope.map.insert(source.clone(), value);
-            interpreter.chain.push(scope);
-            interpreter.eval(*expression.clone(), interpreter.chain.len() - 1)
+        Arc::new(move |value: Value, interpreter: &mut Interpreter| {
+            interpreter.chain.push(Scope::new(scope));
+            let scope = interpreter.chain.len() - 1;
+
+            match &pattern {
+                Some(pattern) => pattern.assign(interpreter, scope, value)?,
+                None => {
+                    interpreter.map(scope).insert(source.clone(), value);
+                }
+            }
+
+            match interpreter.eval(*expression.clone(), scope) {
+                Err(Error::Propagation(value)) => Ok(value),
+                evaluation => evaluation,
+            }
+        })
+    }
+
+    fn param(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        match self.type_expression.clone() {
+            Some(type_expression) => {
+                interpreter.bind(Syntax::TypeExpression(type_expression), scope)
+            }
+            None => interpreter.fresh(),
         }
     }
 }
 
 impl ClosureSyntax {
+    /// Only the last parameter's annotation is split at its first arrow into the parameter and
+    /// the return type, so a function-typed last parameter needs parentheses:
+    /// `f: (Number -> Number) >> f`.
     pub fn parse(
         name: Leaf,
-        type_expression: TypeExpressionSyntax,
+        type_expression: Option<TypeExpressionSyntax>,
         parser: &mut Parser,
     ) -> ClosureSyntax {
+        if parser.current() == Token::Comma {
+            let comma = parser.advance();
+            let next = parser.expect(Token::Identifier);
+            let next_type_expression = match parser.current() {
+                Token::Colon => Some(TypeExpressionSyntax::parse(parser)),
+                _ => None,
+            };
+
+            return ClosureSyntax {
+                name,
+                pattern: None,
+                type_expression,
+                return_type_expression: None,
+                lambda: comma,
+                expression: Box::new(Syntax::Closure(ClosureSyntax::parse(
+                    next,
+                    next_type_expression,
+                    parser,
+                ))),
+            };
+        }
+
+        let (type_expression, return_type_expression) = match type_expression {
+            Some(type_expression) => {
+                let (type_expression, return_type_expression) = type_expression.split();
+                (Some(type_expression), return_type_expression)
+            }
+            None => (None, None),
+        };
+
         ClosureSyntax {
             name,
+            pattern: None,
             type_expression,
+            return_type_expression,
+            lambda: parser.expect(Token::Lambda),
+            expression: Box::new(Syntax::parse(parser)),
+        }
+    }
+
+    pub fn parse_pattern(parser: &mut Parser) -> ClosureSyntax {
+        let pattern = PatternSyntax::parse(parser);
+
+        ClosureSyntax {
+            name: pattern.leaf(),
+            pattern: Some(pattern),
+            type_expression: None,
+            return_type_expression: None,
             lambda: parser.expect(Token::Lambda),
             expression: Box::new(Syntax::parse(parser)),
         }
     }
 
     pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
-        let param = interpreter.bind(Syntax::TypeExpression(self.type_expression.clone()), scope);
-        let mut scope = Scope::new(scope);
+        interpreter.chain.push(Scope::new(scope));
+        let scope = interpreter.chain.len() - 1;
+
+        let param = self.param(interpreter, scope);
+
+        match &self.pattern {
+            Some(pattern) => {
+                pattern.bind(interpreter, scope, param.clone());
+                pattern.check_exhaustive(interpreter, scope);
+            }
+            None => interpreter.declare(scope, self.name, param.clone()),
+        }
+
+        let r#return = match self.return_type_expression.clone() {
+            Some(return_type_expression) => {
+                interpreter.bind(Syntax::TypeExpression(return_type_expression), scope)
+            }
+            None => interpreter.fresh(),
+        };
 
-        scope
-            .type_map
-            .insert(interpreter.source(self.name), param.clone());
+        interpreter.returns.push(r#return.clone());
+        let expression = interpreter.bind(*self.expression.clone(), scope);
+        interpreter.returns.pop();
 
-        interpreter.chain.push(scope);
-        let r#return = interpreter.bind(*self.expression.clone(), interpreter.chain.len() - 1);
-        Type::Closure(box param, box r#return)
+        let r#return =
+            match interpreter.expect(r#return.clone(), expression, self.expression.range()) {
+                true => r#return,
+                false => Type::Error,
+            };
+
+        interpreter.check_generic(scope, self.name.1.range().start..self.lambda.1.range().end);
+        Type::Closure(Box::new(param), Box::new(r#return))
+    }
+
+    pub fn signature(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let param = self.param(interpreter, scope);
+        let r#return = match (
+            self.return_type_expression.clone(),
+            *self.expression.clone(),
+        ) {
+            (Some(return_type_expression), _) => {
+                interpreter.bind(Syntax::TypeExpression(return_type_expression), scope)
+            }
+            (None, Syntax::Closure(closure)) => closure.signature(interpreter, scope),
+            (None, _) => interpreter.fresh(),
+        };
+
+        Type::Closure(Box::new(param), Box::new(r#return))
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        Ok(Value::Closure(self.create_closure(interpreter, scope)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, r#type::Type, run},
+    };
+
+    fn closure(param: Type, r#return: Type) -> Type {
+        Type::Closure(Box::new(param), Box::new(r#return))
+    }
+
+    #[test]
+    fn desugars_multiple_parameters_into_nested_closures() {
+        assert_eq!(
+            run("f = a: Number, b: Number >> a * 10 + b\nf 1 2").unwrap(),
+            run("12").unwrap()
+        );
+        assert_eq!(
+            run("f = a, b, c >> a + b + c\nf 1 2 3").unwrap(),
+            run("6").unwrap()
+        );
+        assert_eq!(
+            run("apply = g: Number -> Number, x: Number >> g x\napply inc 1").unwrap(),
+            run("2").unwrap()
+        );
+        assert_eq!(
+            run("fold (acc: Number, x: Number >> acc + x) 0 [1, 2, 3]").unwrap(),
+            run("6").unwrap()
+        );
+        assert_eq!(
+            run("fold (acc, x >> acc + x) 0 [1, 2, 3]").unwrap(),
+            run("6").unwrap()
+        );
+        assert_eq!(
+            infer("a: Number, b: String >> b").unwrap(),
+            closure(Type::Number, closure(Type::String, Type::String))
+        );
+    }
+
+    #[test]
+    fn splits_the_return_type_off_the_last_parameter_only() {
+        let op = closure(Type::Number, Type::Number);
+        assert_eq!(
+            infer("g: Number -> Number, x: Number >> g x").unwrap(),
+            closure(op.clone(), closure(Type::Number, Type::Number))
+        );
+        assert_eq!(
+            infer("g: Number -> Number, x: Number -> String >> to_string (g x)").unwrap(),
+            closure(op.clone(), closure(Type::Number, Type::String))
+        );
+        assert_eq!(infer("f: Number -> Number >> f").unwrap(), op);
+        assert_eq!(
+            infer("f: (Number -> Number) >> f").unwrap(),
+            closure(op.clone(), op.clone())
+        );
+        assert_eq!(
+            infer("x: Number, g: (Number -> Number) >> g x").unwrap(),
+            closure(Type::Number, closure(op, Type::Number))
+        );
     }
 
-    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Value {
-        Value::Closure(Arc::new(self.create_closure(interpreter, scope)))
+    #[test]
+    fn reports_errors_at_the_individual_parameter() {
+        for (source, location) in [
+            ("f = a: t, b: Number >> a + b", 4..9),
+            ("f = a: Number, b: t >> a + b", 15..22),
+        ] {
+            match infer(source).unwrap_err().as_slice() {
+                [Error::NotGeneric(range, _, _)] => assert_eq!(range.clone(), location),
+                errors => panic!("Expected a generic error, got {:?}", errors),
+            }
+        }
+    }
+
+    #[test]
+    fn checks_the_return_type_after_the_last_parameter() {
+        match infer("f = a: Number, b: Number -> String >> a")
+            .unwrap_err()
+            .as_slice()
+        {
+            [Error::UnexpectedType(_, expected, received)] => {
+                assert_eq!(**expected, Type::String);
+                assert_eq!(**received, Type::Number);
+            }
+            errors => panic!("Expected a type error, got {:?}", errors),
+        }
     }
 }
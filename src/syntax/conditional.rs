@@ -0,0 +1,112 @@
+use crate::interpreter::{r#type::Type, scope::ScopeIndex, value::Evaluation, Interpreter};
+
+use super::{Branch, Leaf, Parser, Syntax, Token};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConditionalSyntax {
+    pub r#if: Leaf,
+    pub condition: Branch,
+    pub then: Leaf,
+    pub consequent: Branch,
+    pub r#else: Leaf,
+    pub alternative: Branch,
+}
+
+impl ConditionalSyntax {
+    pub fn parse(parser: &mut Parser) -> ConditionalSyntax {
+        let r#if = parser.assert(Token::If);
+        let condition = Box::new(Syntax::parse(parser));
+        parser.skip_newlines_before(Token::Then);
+        let then = parser.expect(Token::Then);
+        let consequent = Box::new(Syntax::parse(parser));
+        parser.skip_newlines_before(Token::Else);
+        let r#else = parser.expect(Token::Else);
+        let alternative = Box::new(Syntax::parse(parser));
+
+        ConditionalSyntax {
+            r#if,
+            condition,
+            then,
+            consequent,
+            r#else,
+            alternative,
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let condition = interpreter.bind(*self.condition.clone(), scope);
+        interpreter.expect(Type::Boolean, condition, self.condition.range());
+
+        let consequent = interpreter.bind(*self.consequent.clone(), scope);
+        let alternative = interpreter.bind(*self.alternative.clone(), scope);
+        interpreter.expect(consequent.clone(), alternative, self.alternative.range());
+
+        consequent
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        if interpreter
+            .eval(*self.condition.clone(), scope)?
+            .unwrap_bool()
+        {
+            interpreter.eval(*self.consequent.clone(), scope)
+        } else {
+            interpreter.eval(*self.alternative.clone(), scope)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{number::Number, r#type::Type, run, value::Value},
+    };
+
+    #[test]
+    fn evaluates_the_chosen_branch() {
+        assert_eq!(
+            run("if true then 1 else 2").unwrap(),
+            Value::Number(Number::from(1))
+        );
+        assert_eq!(
+            run("if false\nthen 1\nelse 2").unwrap(),
+            Value::Number(Number::from(2))
+        );
+        assert_eq!(
+            run("f = b: Boolean >> if b then \"y\" else \"n\"\nf false").unwrap(),
+            Value::String(String::from("n"))
+        );
+        assert_eq!(
+            run("iffy = 3\niffy").unwrap(),
+            Value::Number(Number::from(3))
+        );
+    }
+
+    #[test]
+    fn only_evaluates_the_chosen_branch() {
+        assert_eq!(
+            run("if true then 1 else 1 / 0").unwrap(),
+            Value::Number(Number::from(1))
+        );
+    }
+
+    #[test]
+    fn requires_a_boolean_condition_and_matching_branches() {
+        match run("if 1 then 1 else 2").unwrap_err().as_slice() {
+            [Error::UnexpectedType(range, expected, _)] => {
+                assert_eq!(range.clone(), 3..4);
+                assert_eq!(**expected, Type::Boolean);
+            }
+            errors => panic!("Expected a type error, got {:?}", errors),
+        }
+
+        match run("if true then 1 else \"a\"").unwrap_err().as_slice() {
+            [Error::UnexpectedType(_, expected, received)] => {
+                assert_eq!(**expected, Type::Number);
+                assert_eq!(**received, Type::String);
+            }
+            errors => panic!("Expected a type error, got {:?}", errors),
+        }
+    }
+}
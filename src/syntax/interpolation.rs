@@ -0,0 +1,224 @@
+use crate::{
+    error::Error,
+    interpreter::{
+        r#type::Type,
+        scope::ScopeIndex,
+        value::{unescape, Evaluation, Value},
+        Interpreter,
+    },
+};
+
+use super::{Branch, Leaf, Parser, Syntax, Token};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SegmentSyntax {
+    Text(String),
+    Expression(Branch),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct InterpolationSyntax {
+    pub string: Leaf,
+    pub segments: Vec<SegmentSyntax>,
+}
+
+impl InterpolationSyntax {
+    pub fn is_interpolation(parser: &Parser) -> bool {
+        match parser.nodes.get(parser.position) {
+            Some((Token::String, description)) => {
+                let text: String = parser
+                    .source
+                    .chars()
+                    .skip(description.position)
+                    .take(description.length)
+                    .collect();
+                !text.starts_with('r') && text.contains("${")
+            }
+            _ => false,
+        }
+    }
+
+    pub fn parse(parser: &mut Parser) -> InterpolationSyntax {
+        let string = parser.assert(Token::String);
+        let chars: Vec<char> = parser.source.chars().collect();
+
+        let text = &chars[string.1.range()];
+        let delimiter = match text.starts_with(&['"'; 3]) {
+            true => 3,
+            false => 1,
+        };
+        let start = string.1.position + delimiter;
+        let end = match text.ends_with(&['"']) && text.len() >= delimiter * 2 {
+            true => string.1.range().end - delimiter,
+            false => string.1.range().end,
+        };
+
+        let mut segments = vec![];
+        let mut text = String::new();
+        let mut index = start;
+        while index < end {
+            match chars[index] {
+                '\\' => {
+                    text.extend(chars[index..end.min(index + 2)].iter());
+                    index += 2;
+                }
+                '$' if chars.get(index + 1) == Some(&'{') => {
+                    let close = InterpolationSyntax::closing(&chars, index + 2, end);
+                    segments.push(SegmentSyntax::Text(unescape(&text)));
+                    if let Some(expression) =
+                        InterpolationSyntax::expression(parser, &chars, index + 2, close)
+                    {
+                        segments.push(SegmentSyntax::Expression(Box::new(expression)));
+                    }
+                    text.clear();
+                    index = close + 1;
+                }
+                char => {
+                    text.push(char);
+                    index += 1;
+                }
+            }
+        }
+        segments.push(SegmentSyntax::Text(unescape(&text)));
+
+        InterpolationSyntax { string, segments }
+    }
+
+    fn expression(parser: &mut Parser, chars: &[char], start: usize, end: usize) -> Option<Syntax> {
+        let source: String = chars[..end].iter().collect();
+        let mut expression_parser = Parser::new(&source, start);
+        parser.errors.append(&mut expression_parser.errors);
+
+        if expression_parser.nodes.is_empty() {
+            parser
+                .errors
+                .push(Error::EmptyInterpolation(start - 2..end + 1));
+            return None;
+        }
+
+        let expression = Syntax::parse(&mut expression_parser);
+        if expression_parser.current() != Token::EndOfFile {
+            expression_parser.expect(Token::CloseBrace);
+        }
+
+        parser.errors.append(&mut expression_parser.errors);
+        Some(expression)
+    }
+
+    fn closing(chars: &[char], mut index: usize, end: usize) -> usize {
+        let mut depth = 1;
+        while index < end {
+            match chars[index] {
+                '{' => depth += 1,
+                '}' if depth == 1 => return index,
+                '}' => depth -= 1,
+                '"' => index = InterpolationSyntax::skip_string(chars, index + 1, end),
+                _ => (),
+            }
+            index += 1;
+        }
+        end
+    }
+
+    fn skip_string(chars: &[char], mut index: usize, end: usize) -> usize {
+        while index < end {
+            match chars[index] {
+                '\\' => index += 1,
+                '$' if chars.get(index + 1) == Some(&'{') => {
+                    index = InterpolationSyntax::closing(chars, index + 2, end)
+                }
+                '"' => return index,
+                _ => (),
+            }
+            index += 1;
+        }
+        end
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        for segment in &self.segments {
+            if let SegmentSyntax::Expression(expression) = segment {
+                let r#type = interpreter.bind(*expression.clone(), scope);
+                interpreter.expect(Type::String, r#type, expression.range());
+            }
+        }
+
+        Type::String
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let mut string = String::new();
+        for segment in &self.segments {
+            match segment {
+                SegmentSyntax::Text(text) => string.push_str(text),
+                SegmentSyntax::Expression(expression) => string.push_str(
+                    &interpreter
+                        .eval(*expression.clone(), scope)?
+                        .unwrap_string(),
+                ),
+            }
+        }
+
+        Ok(Value::String(string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{run, value::Value},
+        syntax::Token,
+    };
+
+    #[test]
+    fn evaluates_embedded_expressions() {
+        assert_eq!(
+            run("name = \"world\"\n\"hello ${name}!\"").unwrap(),
+            Value::String(String::from("hello world!"))
+        );
+        assert_eq!(
+            run("\"é ${to_string (1 + 2)}\"").unwrap(),
+            Value::String(String::from("é 3"))
+        );
+        assert_eq!(
+            run("\"${\"${\"nested\"}\"}\"").unwrap(),
+            Value::String(String::from("nested"))
+        );
+    }
+
+    #[test]
+    fn reports_names_after_multibyte_characters() {
+        match run("\"é ${name}\"").unwrap_err().as_slice() {
+            [Error::UnknownName(range, name)] => {
+                assert_eq!(range.clone(), 5..9);
+                assert_eq!(name, "name");
+            }
+            errors => panic!("Expected an unknown name, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn reports_empty_interpolations() {
+        for (source, range) in [
+            ("\"${}\"", 1..4),
+            ("\"${#x}\"", 1..6),
+            ("\"${ #[ c ]# }\"", 1..13),
+        ] {
+            match run(source).unwrap_err().as_slice() {
+                [Error::EmptyInterpolation(location)] => assert_eq!(location.clone(), range),
+                errors => panic!("Expected an empty interpolation, got {:?}", errors),
+            }
+        }
+    }
+
+    #[test]
+    fn reports_incomplete_expressions_at_the_closing_brace() {
+        match run("\"a ${1 +} b\"").unwrap_err().as_slice() {
+            [Error::UnexpectedToken(range, Token::Identifier, Token::EndOfFile)] => {
+                assert_eq!(range.clone(), 8..8)
+            }
+            errors => panic!("Expected an unexpected end of file, got {:?}", errors),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::interpreter::{
+    r#type::Type,
+    scope::{Scope, ScopeIndex},
+    value::Evaluation,
+    Interpreter,
+};
+
+use super::{Branch, Leaf, Parser, ProgramSyntax, Syntax, Token};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct LetSyntax {
+    pub r#let: Leaf,
+    pub bindings: ProgramSyntax,
+    pub r#in: Leaf,
+    pub expression: Branch,
+}
+
+impl LetSyntax {
+    pub fn parse(parser: &mut Parser) -> LetSyntax {
+        let r#let = parser.assert(Token::Let);
+        let mut bindings = vec![];
+
+        ProgramSyntax::skip_separators(parser);
+        while parser.current() != Token::In && parser.current() != Token::EndOfFile {
+            bindings.push(Syntax::parse(parser));
+            ProgramSyntax::skip_separators(parser);
+        }
+
+        let r#in = parser.expect(Token::In);
+        parser.skip_newlines();
+
+        LetSyntax {
+            r#let,
+            bindings: ProgramSyntax(bindings),
+            r#in,
+            expression: Box::new(Syntax::parse(parser)),
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        interpreter.chain.push(Scope::new(scope));
+        let scope = interpreter.chain.len() - 1;
+
+        self.bindings.bind(interpreter, scope);
+        interpreter.bind(*self.expression.clone(), scope)
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        interpreter.chain.push(Scope::new(scope));
+        let scope = interpreter.chain.len() - 1;
+
+        self.bindings.eval(interpreter, scope)?;
+        interpreter.eval(*self.expression.clone(), scope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{number::Number, run, value::Value},
+    };
+
+    #[test]
+    fn evaluates_bindings_in_a_new_scope() {
+        assert_eq!(
+            run("let\n  a = 1\n  b = a + 1\nin a + b").unwrap(),
+            Value::Number(Number::from(3))
+        );
+        assert!(matches!(
+            run("x = let y = 1 in y\ny").unwrap_err().as_slice(),
+            [Error::UnknownName(_, _)]
+        ));
+    }
+
+    #[test]
+    fn continues_the_body_on_the_next_line() {
+        assert_eq!(
+            run("let x = 1 in\nx + 1").unwrap(),
+            Value::Number(Number::from(2))
+        );
+        assert_eq!(
+            run("let\n  x = 1\nin\n\n  x + 1").unwrap(),
+            Value::Number(Number::from(2))
+        );
+    }
+
+    #[test]
+    fn shadows_outer_bindings() {
+        assert_eq!(
+            run("y = 1\nlet y = 2 in y").unwrap(),
+            Value::Number(Number::from(2))
+        );
+        assert_eq!(
+            run("let inc = x >> x in inc 1").unwrap(),
+            Value::Number(Number::from(1))
+        );
+        assert_eq!(
+            run("y = 1\nz = let y = \"a\" in y\ny + 1").unwrap(),
+            Value::Number(Number::from(2))
+        );
+    }
+
+    #[test]
+    fn rejects_reassignment_within_the_same_let() {
+        match run("let y = 1\n y = 2 in y").unwrap_err().as_slice() {
+            [Error::Reassignment(range, name)] => {
+                assert_eq!(range.clone(), 11..12);
+                assert_eq!(name, "y");
+            }
+            errors => panic!("Expected a reassignment, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn accepts_any_program_statement_as_a_binding() {
+        assert_eq!(
+            run("let (q, r) = divmod 7 2 in q * 10 + r").unwrap(),
+            Value::Number(Number::from(31))
+        );
+        assert_eq!(
+            run("let sq: Number -> Number = n >> n * n in sq 4").unwrap(),
+            Value::Number(Number::from(16))
+        );
+        assert_eq!(
+            run("let type Pt = Pt Number Number; norm = p >> match p | Pt x y >> x + y in norm (Pt 1 2)")
+                .unwrap(),
+            Value::Number(Number::from(3))
+        );
+        assert_eq!(
+            run("f = x >> let id = y >> y in (id x, id \"s\")\nf 1").unwrap(),
+            Value::Tuple(vec![
+                Value::Number(Number::from(1)),
+                Value::String(String::from("s"))
+            ])
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    error::Error,
+    interpreter::{
+        list::List,
+        r#type::Type,
+        scope::ScopeIndex,
+        value::{Evaluation, Value},
+        Interpreter,
+    },
+};
+
+use super::{Leaf, Parser, Syntax, Token};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ListSyntax {
+    pub open: Leaf,
+    pub elements: Vec<Syntax>,
+    pub close: Leaf,
+}
+
+impl ListSyntax {
+    pub fn parse(parser: &mut Parser) -> ListSyntax {
+        let open = parser.assert(Token::OpenBracket);
+        let mut elements = vec![];
+        let sequence = std::mem::replace(&mut parser.sequence, true);
+
+        parser.skip_newlines_before(Token::CloseBracket);
+        while parser.current() != Token::CloseBracket && parser.current() != Token::EndOfFile {
+            elements.push(Syntax::parse(parser));
+
+            parser.skip_newlines_before(Token::Comma);
+            if parser.current() != Token::Comma {
+                break;
+            }
+
+            parser.advance();
+            parser.skip_newlines_before(Token::CloseBracket);
+        }
+
+        parser.skip_newlines_before(Token::CloseBracket);
+        parser.sequence = sequence;
+
+        ListSyntax {
+            open,
+            elements,
+            close: parser.expect(Token::CloseBracket),
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let element = interpreter.fresh();
+
+        for syntax in self.elements.iter() {
+            let r#type = interpreter.bind(syntax.clone(), scope);
+            interpreter.expect(element.clone(), r#type, syntax.range());
+        }
+
+        Type::list(element)
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let elements = self
+            .elements
+            .iter()
+            .map(|element| interpreter.eval(element.clone(), scope))
+            .collect::<Result<Vec<Value>, Error>>()?;
+
+        Ok(Value::List(List::from(elements)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, r#type::Type},
+    };
+
+    #[test]
+    fn infers_element_types() {
+        assert_eq!(infer("[1, 2, 3]").unwrap(), Type::list(Type::Number));
+        assert_eq!(
+            infer("[[1], []]").unwrap(),
+            Type::list(Type::list(Type::Number))
+        );
+        assert!(matches!(
+            infer("[]").unwrap(),
+            Type::Data(_, arguments) if matches!(arguments[0], Type::Variable(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_mixed_elements() {
+        match infer("[1, \"a\"]").unwrap_err().as_slice() {
+            [Error::UnexpectedType(range, expected, received)] => {
+                assert_eq!(range.clone(), 4..7);
+                assert_eq!(**expected, Type::Number);
+                assert_eq!(**received, Type::String);
+            }
+            errors => panic!("Expected a type error, got {:?}", errors),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::interpreter::{
+    number::Number,
     r#type::Type,
-    value::{inner_string, Value},
+    value::{inner_char, inner_string, Value},
     Interpreter,
 };
 
@@ -27,9 +28,10 @@ impl LiteralSyntax {
     pub fn bind(&self) -> Type {
         match self.token() {
             Token::String => Type::String,
+            Token::Char => Type::Char,
             Token::Number => Type::Number,
             Token::Boolean => Type::Boolean,
-            Token::None => Type::None,
+            Token::None => Type::Unit,
             _ => panic!(),
         }
     }
@@ -37,10 +39,38 @@ impl LiteralSyntax {
     pub fn eval(&self, interpreter: &mut Interpreter) -> Value {
         match self.token() {
             Token::String => Value::String(inner_string(interpreter.source(self.0))),
-            Token::Number => Value::Number(interpreter.source(self.0).parse::<usize>().unwrap()),
+            Token::Char => Value::Char(inner_char(interpreter.source(self.0))),
+            Token::Number => Value::Number(Number::parse(&interpreter.source(self.0))),
             Token::Boolean => Value::Boolean(interpreter.source(self.0).parse::<bool>().unwrap()),
             Token::None => Value::None,
             _ => panic!(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, r#type::Type, run, value::Value},
+    };
+
+    #[test]
+    fn types_each_kind_of_literal() {
+        assert_eq!(infer("1.5").unwrap(), Type::Number);
+        assert_eq!(infer("\"a\"").unwrap(), Type::String);
+        assert_eq!(infer("'a'").unwrap(), Type::Char);
+        assert_eq!(infer("true").unwrap(), Type::Boolean);
+        assert_eq!(infer("c: Char = 'x'\nc").unwrap(), Type::Char);
+    }
+
+    #[test]
+    fn evaluates_character_literals() {
+        assert_eq!(run("'é'").unwrap(), Value::Char('é'));
+        assert_eq!(run("'\\u{41}'").unwrap(), Value::Char('A'));
+        assert!(matches!(
+            run("'a' ++ \"b\"").unwrap_err().as_slice(),
+            [Error::UnexpectedType(_, _, _)]
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{
+    error::Error,
+    interpreter::{
+        r#type::Type,
+        scope::{Scope, ScopeIndex},
+        value::Evaluation,
+        Interpreter,
+    },
+};
+
+use super::{Branch, Leaf, Parser, PatternSyntax, Syntax, Token};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct MatchSyntax {
+    pub r#match: Leaf,
+    pub expression: Branch,
+    pub arms: Vec<ArmSyntax>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ArmSyntax {
+    pub bar: Leaf,
+    pub pattern: PatternSyntax,
+    pub lambda: Leaf,
+    pub expression: Branch,
+}
+
+impl MatchSyntax {
+    pub fn parse(parser: &mut Parser) -> MatchSyntax {
+        let r#match = parser.assert(Token::Match);
+        let expression = Box::new(Syntax::parse(parser));
+        let mut arms = vec![];
+
+        parser.skip_newlines_before(Token::Bar);
+        if parser.current() != Token::Bar {
+            parser.expect(Token::Bar);
+        }
+
+        while parser.current() == Token::Bar {
+            arms.push(ArmSyntax {
+                bar: parser.advance(),
+                pattern: PatternSyntax::parse(parser),
+                lambda: parser.expect(Token::Lambda),
+                expression: Box::new(Syntax::parse(parser)),
+            });
+            parser.skip_newlines_before(Token::Bar);
+        }
+
+        MatchSyntax {
+            r#match,
+            expression,
+            arms,
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let expression = interpreter.bind(*self.expression.clone(), scope);
+        let r#type = interpreter.fresh();
+        let mut rows = vec![];
+
+        for arm in self.arms.iter() {
+            interpreter.chain.push(Scope::new(scope));
+            let arm_scope = interpreter.chain.len() - 1;
+
+            arm.pattern.bind(interpreter, arm_scope, expression.clone());
+            let body = interpreter.bind(*arm.expression.clone(), arm_scope);
+            interpreter.expect(r#type.clone(), body, arm.expression.range());
+
+            rows.push(vec![arm.pattern.pattern(interpreter)]);
+        }
+
+        if interpreter.prune(expression) == Type::Error {
+            return r#type;
+        }
+
+        if let Some(witness) = interpreter.missing(scope, &rows, 1) {
+            interpreter.error(Error::NonExhaustiveMatch(
+                interpreter.range(self.r#match).start..self.expression.range().end,
+                format!("{:?}", witness[0]),
+            ));
+        }
+
+        r#type
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let value = interpreter.eval(*self.expression.clone(), scope)?;
+
+        for arm in self.arms.iter() {
+            let mut bindings = vec![];
+
+            if arm.pattern.matches(interpreter, &value, &mut bindings) {
+                interpreter.chain.push(Scope::new(scope));
+                let arm_scope = interpreter.chain.len() - 1;
+
+                for (name, value) in bindings {
+                    interpreter.map(arm_scope).insert(name, value);
+                }
+
+                return interpreter.eval(*arm.expression.clone(), arm_scope);
+            }
+        }
+
+        Err(Error::RuntimeError(
+            interpreter.range(self.r#match).start..self.expression.range().end,
+            String::from("No arm matched the value"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{number::Number, run, value::Value},
+    };
+
+    const SHAPE: &str = "type Shape = Circle Number | Square Number\n";
+    const CHAIN: &str = "type Chain a = Nil | Cons a (Chain a)\n";
+
+    fn witness(source: &str) -> String {
+        match run(source).unwrap_err().as_slice() {
+            [Error::NonExhaustiveMatch(_, witness)] => witness.clone(),
+            errors => panic!("Expected a non-exhaustive match, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn evaluates_the_first_matching_arm() {
+        assert_eq!(
+            run(&format!(
+                "{}area = s >> match s\n  | Circle r >> r * r\n  | Square w >> w * 4\narea (Square 3)",
+                SHAPE
+            ))
+            .unwrap(),
+            Value::Number(Number::from(12))
+        );
+        assert_eq!(
+            run(&format!(
+                "{}len = l >> match l | Nil >> 0 | Cons _ rest >> inc (len rest)\nlen (Cons 1 (Cons 2 Nil))",
+                CHAIN
+            ))
+            .unwrap(),
+            Value::Number(Number::from(2))
+        );
+        assert_eq!(
+            run("match 1 | 1 >> 2 | n >> n").unwrap(),
+            Value::Number(Number::from(2))
+        );
+    }
+
+    #[test]
+    fn reports_uncovered_patterns() {
+        assert_eq!(
+            witness(&format!("{}s = Circle 1\nmatch s | Circle r >> r", SHAPE)),
+            "Square _"
+        );
+        assert_eq!(
+            witness("match Some true | Some true >> 1 | None >> 0"),
+            "Some false"
+        );
+        assert_eq!(witness("match 1 | 1 >> 2"), "_");
+    }
+
+    #[test]
+    fn checks_constructor_arguments() {
+        assert!(matches!(
+            run(&format!("{}x: Chain = Nil", CHAIN))
+                .unwrap_err()
+                .as_slice(),
+            [Error::WrongArguments(_, _, 1, 0)]
+        ));
+        assert!(matches!(
+            run(&format!(
+                "{}match Circle 1 | Circle \"a\" >> 1 | _ >> 2",
+                SHAPE
+            ))
+            .unwrap_err()
+            .as_slice(),
+            [Error::UnexpectedType(_, _, _)]
+        ));
+    }
+}
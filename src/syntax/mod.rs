@@ -1,19 +1,47 @@
+pub mod access;
 pub mod assignment;
+pub mod binary;
 pub mod call;
 pub mod closure;
+pub mod conditional;
+pub mod interpolation;
+pub mod let_expression;
+pub mod list;
 pub mod literal;
+pub mod match_expression;
 pub mod name;
 pub mod parser;
+pub mod pattern;
+pub mod program;
+pub mod record;
 pub mod token;
+pub mod try_expression;
+pub mod tuple;
+pub mod type_definition;
 pub mod type_expression;
 
+use std::ops::Range;
+
+pub use self::access::*;
 pub use self::assignment::*;
+pub use self::binary::*;
 pub use self::call::*;
 pub use self::closure::*;
+pub use self::conditional::*;
+pub use self::interpolation::*;
+pub use self::let_expression::*;
+pub use self::list::*;
 pub use self::literal::*;
+pub use self::match_expression::*;
 pub use self::name::*;
 pub use self::parser::*;
+pub use self::pattern::*;
+pub use self::program::*;
+pub use self::record::*;
 pub use self::token::*;
+pub use self::try_expression::*;
+pub use self::tuple::*;
+pub use self::type_definition::*;
 pub use self::type_expression::*;
 
 #[derive(Debug, PartialEq, Clone)]
@@ -24,6 +52,18 @@ pub enum Syntax {
     Closure(ClosureSyntax),
     Assignment(AssignmentSyntax),
     TypeExpression(TypeExpressionSyntax),
+    Program(ProgramSyntax),
+    Conditional(ConditionalSyntax),
+    TypeDefinition(TypeDefinitionSyntax),
+    Match(MatchSyntax),
+    Record(RecordSyntax),
+    Access(AccessSyntax),
+    Tuple(TupleSyntax),
+    List(ListSyntax),
+    Binary(BinarySyntax),
+    Let(LetSyntax),
+    Interpolation(InterpolationSyntax),
+    Try(TrySyntax),
 }
 
 impl Syntax {
@@ -36,15 +76,89 @@ impl Syntax {
                 type_expression,
                 parser,
             )),
-            _ => Syntax::Closure(ClosureSyntax::parse(name, type_expression, parser)),
+            _ => Syntax::Closure(ClosureSyntax::parse(name, Some(type_expression), parser)),
         }
     }
 
     pub fn parse(parser: &mut Parser) -> Syntax {
+        if parser.current() == Token::If {
+            return Syntax::Conditional(ConditionalSyntax::parse(parser));
+        }
+
+        if parser.current() == Token::Match {
+            return Syntax::Match(MatchSyntax::parse(parser));
+        }
+
+        if parser.current() == Token::Let {
+            return Syntax::Let(LetSyntax::parse(parser));
+        }
+
+        if parser.current() == Token::Type {
+            return Syntax::TypeDefinition(TypeDefinitionSyntax::parse(parser));
+        }
+
+        if parser.current() == Token::OpenParenthesis {
+            match parser.peek(parser.closing() + 1) {
+                Token::Lambda => return Syntax::Closure(ClosureSyntax::parse_pattern(parser)),
+                Token::Equals => {
+                    return Syntax::Assignment(AssignmentSyntax::parse_pattern(parser))
+                }
+                _ => (),
+            }
+        }
+
         match parser.peek(1) {
             Token::Colon => Syntax::parse_with_type(parser),
+            Token::Lambda => Syntax::Closure(ClosureSyntax::parse(
+                parser.expect(Token::Identifier),
+                None,
+                parser,
+            )),
             Token::Equals => Syntax::Assignment(AssignmentSyntax::parse(parser)),
-            _ => CallSyntax::parse(parser),
+            Token::Comma if !parser.sequence && parser.parameters() => Syntax::Closure(
+                ClosureSyntax::parse(parser.expect(Token::Identifier), None, parser),
+            ),
+            _ => BinarySyntax::parse(parser),
+        }
+    }
+}
+
+impl Syntax {
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            Syntax::Name(name) => name.0 .1.range(),
+            Syntax::Literal(literal) => literal.description().range(),
+            Syntax::Call(call) => call.range(),
+            Syntax::Closure(closure) => {
+                closure.name.1.range().start..closure.expression.range().end
+            }
+            Syntax::Assignment(assignment) => {
+                assignment.name.1.range().start..assignment.expression.range().end
+            }
+            Syntax::TypeExpression(type_expression) => type_expression.range(),
+            Syntax::Program(program) => match (program.0.first(), program.0.last()) {
+                (Some(first), Some(last)) => first.range().start..last.range().end,
+                _ => 0..0,
+            },
+            Syntax::Conditional(conditional) => {
+                conditional.r#if.1.range().start..conditional.alternative.range().end
+            }
+            Syntax::TypeDefinition(type_definition) => match type_definition.variants.last() {
+                Some(variant) => type_definition.r#type.1.range().start..variant.range().end,
+                None => type_definition.r#type.1.range(),
+            },
+            Syntax::Record(record) => record.open.1.range().start..record.close.1.range().end,
+            Syntax::List(list) => list.open.1.range().start..list.close.1.range().end,
+            Syntax::Tuple(tuple) => tuple.open.1.range().start..tuple.close.1.range().end,
+            Syntax::Access(access) => access.expression.range().start..access.name.1.range().end,
+            Syntax::Binary(binary) => binary.left.range().start..binary.right.range().end,
+            Syntax::Let(r#let) => r#let.r#let.1.range().start..r#let.expression.range().end,
+            Syntax::Interpolation(interpolation) => interpolation.string.1.range(),
+            Syntax::Try(r#try) => r#try.expression.range().start..r#try.question.1.range().end,
+            Syntax::Match(r#match) => match r#match.arms.last() {
+                Some(arm) => r#match.r#match.1.range().start..arm.expression.range().end,
+                None => r#match.r#match.1.range().start..r#match.expression.range().end,
+            },
         }
     }
 }
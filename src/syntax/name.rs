@@ -1,6 +1,6 @@
 use crate::{
     error::Error,
-    interpreter::{r#type::Type, scope::ScopeIndex, value::Value, Interpreter},
+    interpreter::{r#type::Type, scope::ScopeIndex, value::Evaluation, Interpreter},
 };
 
 use super::{Leaf, Node};
@@ -14,7 +14,7 @@ impl NameSyntax {
             interpreter.range(self.0),
             interpreter.source(self.0),
         ));
-        Type::None
+        Type::Error
     }
 
     fn node(&self) -> Node {
@@ -25,12 +25,62 @@ impl NameSyntax {
 impl NameSyntax {
     pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
         match interpreter.lookup(scope, self.node()) {
-            Some(value) => value.clone(),
+            Some(_) if interpreter.is_undefined(scope, self.node()) => {
+                interpreter.error(Error::UsedBeforeDefinition(
+                    interpreter.range(self.0),
+                    interpreter.source(self.0),
+                ));
+                Type::Error
+            }
+            Some(r#type) => interpreter.instantiate_name(scope, self.node(), r#type),
             None => self.unknown_name_error(interpreter),
         }
     }
 
-    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Value {
-        interpreter.get(scope, self.node()).unwrap().clone()
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        match interpreter.get(scope, self.node()) {
+            Some(value) => Ok(value),
+            None => Err(Error::RuntimeError(
+                interpreter.range(self.0),
+                format!(
+                    "\"{}\" was used before it was defined",
+                    interpreter.source(self.0)
+                ),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, run},
+    };
+
+    fn undefined(source: &str) -> std::ops::Range<usize> {
+        match infer(source).unwrap_err().as_slice() {
+            [Error::UsedBeforeDefinition(range, _)] => range.clone(),
+            errors => panic!("Expected a definition error, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn reports_names_used_before_their_definition() {
+        assert_eq!(undefined("a = f 1\nf = x >> x"), 4..5);
+        assert_eq!(undefined("let a = g 2\n g = x >> x in a"), 8..9);
+        assert_eq!(undefined("x: Number = y\ny: Number = 1"), 12..13);
+        assert_eq!(undefined("x: Number = x + 1"), 12..13);
+        assert_eq!(
+            undefined("f = x >> let a = g x\n g = y >> y in a\nf 1"),
+            17..18
+        );
+    }
+
+    #[test]
+    fn allows_closures_to_refer_to_later_definitions() {
+        assert!(run("f = x >> g x\ng = x >> x\nf 1").is_ok());
+        assert!(run("let f = x >> g x\n g = x >> x in f 1").is_ok());
+        assert!(run("f: Number -> Number = x >> if x == 0 then 0 else f (x - 1)\nf 3").is_ok());
     }
 }
@@ -3,9 +3,11 @@ use crate::error::Error;
 
 #[derive(Debug)]
 pub struct Parser {
+    pub source: String,
     pub position: usize,
     pub nodes: Vec<Node>,
     pub errors: Vec<Error>,
+    pub sequence: bool,
 }
 
 impl Parser {
@@ -13,9 +15,11 @@ impl Parser {
         let lexer = tokenize(source, start);
         let errors = lexer.errors.clone();
         Parser {
+            source: String::from(source),
             position: 0,
             nodes: lexer.into(),
             errors,
+            sequence: false,
         }
     }
 
@@ -26,7 +30,7 @@ impl Parser {
         }
     }
 
-    pub fn next(&mut self) -> Node {
+    pub fn advance(&mut self) -> Node {
         self.position += 1;
         match self.nodes.get(self.position - 1) {
             Some(_) => self.nodes[self.position - 1],
@@ -34,7 +38,6 @@ impl Parser {
                 Token::EndOfFile,
                 Description {
                     position: self.nodes.last().unwrap().1.position
-                        + self.nodes.last().unwrap().1.position
                         + self.nodes.last().unwrap().1.length,
                     length: 0,
                 },
@@ -43,7 +46,7 @@ impl Parser {
     }
 
     pub fn expect(&mut self, expected: Token) -> Node {
-        let (token, description) = self.next();
+        let (token, description) = self.advance();
         if token == expected {
             (token, description)
         } else {
@@ -57,7 +60,7 @@ impl Parser {
     }
 
     pub fn assert(&mut self, expected: Token) -> Node {
-        let (token, description) = self.next();
+        let (token, description) = self.advance();
         if token == expected {
             (token, description)
         } else {
@@ -72,20 +75,119 @@ impl Parser {
         }
     }
 
+    pub fn skip_newlines(&mut self) {
+        while self.current() == Token::Newline {
+            self.advance();
+        }
+    }
+
+    pub fn skip_newlines_before(&mut self, expected: Token) {
+        let mut ahead = 0;
+        while self.peek(ahead) == Token::Newline {
+            ahead += 1
+        }
+
+        if self.peek(ahead) == expected {
+            self.position += ahead
+        }
+    }
+
+    pub fn closing(&self) -> usize {
+        let mut depth = 0;
+        let mut ahead = 0;
+
+        loop {
+            match self.peek(ahead) {
+                Token::OpenParenthesis => depth += 1,
+                Token::CloseParenthesis if depth == 1 => return ahead,
+                Token::CloseParenthesis => depth -= 1,
+                Token::EndOfFile => return ahead,
+                _ => (),
+            }
+
+            ahead += 1
+        }
+    }
+
+    pub fn parameters(&self) -> bool {
+        let mut ahead = 0;
+
+        loop {
+            if self.peek(ahead) != Token::Identifier {
+                return false;
+            }
+
+            match self.peek(ahead + 1) {
+                Token::Comma => ahead += 2,
+                Token::Lambda | Token::Colon => return ahead > 0,
+                _ => return false,
+            }
+        }
+    }
+
     pub fn primary(&mut self) -> Syntax {
+        let mut expression = self.atom();
+
+        loop {
+            expression = match self.current() {
+                Token::Dot => Syntax::Access(AccessSyntax {
+                    expression: Box::new(expression),
+                    dot: self.advance(),
+                    name: self.expect(Token::Identifier),
+                }),
+                _ => break,
+            };
+        }
+
+        expression
+    }
+
+    fn atom(&mut self) -> Syntax {
+        if self.current() == Token::OpenBracket {
+            return Syntax::List(ListSyntax::parse(self));
+        }
+
+        if self.current() == Token::OpenBrace {
+            return Syntax::Record(RecordSyntax::parse(self));
+        }
+
         if self.current() == Token::OpenParenthesis {
-            self.next();
+            let open = self.advance();
+            let parameters = self.parameters();
+            let sequence = std::mem::replace(&mut self.sequence, !parameters);
             let expression = Syntax::parse(self);
-            self.expect(Token::CloseParenthesis);
-            return expression;
+
+            if self.current() != Token::Comma {
+                self.sequence = sequence;
+                self.expect(Token::CloseParenthesis);
+                return expression;
+            }
+
+            let mut elements = vec![expression];
+            while self.current() == Token::Comma {
+                self.advance();
+                elements.push(Syntax::parse(self));
+            }
+
+            self.sequence = sequence;
+            return Syntax::Tuple(TupleSyntax {
+                open,
+                elements,
+                close: self.expect(Token::CloseParenthesis),
+            });
+        }
+
+        if InterpolationSyntax::is_interpolation(self) {
+            return Syntax::Interpolation(InterpolationSyntax::parse(self));
         }
 
         if self.current() == Token::Number
             || self.current() == Token::String
+            || self.current() == Token::Char
             || self.current() == Token::Boolean
             || self.current() == Token::None
         {
-            return Syntax::Literal(LiteralSyntax(self.next()));
+            return Syntax::Literal(LiteralSyntax(self.advance()));
         }
 
         Syntax::Name(NameSyntax(self.expect(Token::Identifier)))
@@ -94,7 +196,7 @@ impl Parser {
 
 pub fn parse(source: &str, start: usize) -> (Syntax, Vec<Error>) {
     let mut parser = Parser::new(source, start);
-    let syntax = Syntax::parse(&mut parser);
+    let syntax = Syntax::Program(ProgramSyntax::parse(&mut parser));
     (syntax, parser.errors)
 }
 
@@ -105,7 +207,7 @@ impl From<Lexer> for Vec<Node> {
             .into_iter()
             .enumerate()
             .map(|(i, t)| (t, lexer.description[i]))
-            .filter(|(t, _)| *t != Token::Space)
+            .filter(|(t, _)| !t.is_trivia())
             .collect::<Vec<Node>>()
     }
 }
@@ -113,3 +215,69 @@ impl From<Lexer> for Vec<Node> {
 pub type Leaf = (Token, Description);
 pub type Node = Leaf;
 pub type Branch = Box<Syntax>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statements(source: &str) -> Vec<Syntax> {
+        let (syntax, errors) = parse(source, 0);
+        assert!(errors.is_empty(), "{:?}", errors);
+        match syntax {
+            Syntax::Program(program) => program.0,
+            syntax => panic!("Expected a program, got {:?}", syntax),
+        }
+    }
+
+    #[test]
+    fn parses_parenthesised_commas_as_tuples() {
+        match &statements("(inc, (x >> x))")[0] {
+            Syntax::Tuple(tuple) => {
+                assert_eq!(tuple.elements.len(), 2);
+                assert!(matches!(tuple.elements[0], Syntax::Name(_)));
+                assert!(matches!(tuple.elements[1], Syntax::Closure(_)));
+            }
+            syntax => panic!("Expected a tuple, got {:?}", syntax),
+        }
+
+        assert!(matches!(&statements("(1, x >> x)")[0], Syntax::Tuple(_)));
+
+        match &statements("go = x >> (x, (y >> y))")[0] {
+            Syntax::Assignment(assignment) => match assignment.expression.as_ref() {
+                Syntax::Closure(closure) => {
+                    assert!(matches!(closure.expression.as_ref(), Syntax::Tuple(_)))
+                }
+                syntax => panic!("Expected a closure, got {:?}", syntax),
+            },
+            syntax => panic!("Expected an assignment, got {:?}", syntax),
+        }
+    }
+
+    #[test]
+    fn parses_multiple_parameters_outside_parentheses() {
+        match &statements("f = a, b >> a")[0] {
+            Syntax::Assignment(assignment) => match assignment.expression.as_ref() {
+                Syntax::Closure(closure) => {
+                    assert!(matches!(closure.expression.as_ref(), Syntax::Closure(_)))
+                }
+                syntax => panic!("Expected a closure, got {:?}", syntax),
+            },
+            syntax => panic!("Expected an assignment, got {:?}", syntax),
+        }
+
+        assert!(matches!(
+            &statements("(a: Number, b: Number >> a)")[0],
+            Syntax::Closure(_)
+        ));
+    }
+
+    #[test]
+    fn parses_parenthesised_parameter_lists_as_closures() {
+        match &statements("(acc, x >> acc + x)")[0] {
+            Syntax::Closure(closure) => {
+                assert!(matches!(closure.expression.as_ref(), Syntax::Closure(_)))
+            }
+            syntax => panic!("Expected a closure, got {:?}", syntax),
+        }
+    }
+}
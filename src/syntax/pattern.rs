@@ -0,0 +1,270 @@
+use std::ops::Range;
+
+use crate::{
+    error::Error,
+    interpreter::{
+        exhaustiveness::Pattern, r#type::Type, scope::ScopeIndex, value::Value, Interpreter,
+    },
+};
+
+use super::{Leaf, LiteralSyntax, Parser, Token};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum PatternSyntax {
+    Identifier(Leaf, Vec<PatternSyntax>),
+    Literal(Leaf),
+    Group(Leaf, Box<PatternSyntax>, Leaf),
+    Tuple(Leaf, Vec<PatternSyntax>, Leaf),
+}
+
+impl PatternSyntax {
+    fn is_wildcard(interpreter: &Interpreter, name: Leaf) -> bool {
+        interpreter.source(name) == "_"
+    }
+
+    fn is_constructor(interpreter: &Interpreter, name: Leaf) -> bool {
+        match interpreter.source(name).chars().next() {
+            Some(first) => first.is_uppercase(),
+            None => false,
+        }
+    }
+
+    fn primary(parser: &mut Parser) -> PatternSyntax {
+        match parser.current() {
+            Token::OpenParenthesis => {
+                let open = parser.advance();
+                let pattern = PatternSyntax::parse(parser);
+
+                if parser.current() != Token::Comma {
+                    let close = parser.expect(Token::CloseParenthesis);
+                    return PatternSyntax::Group(open, Box::new(pattern), close);
+                }
+
+                let mut elements = vec![pattern];
+                while parser.current() == Token::Comma {
+                    parser.advance();
+                    elements.push(PatternSyntax::parse(parser));
+                }
+
+                PatternSyntax::Tuple(open, elements, parser.expect(Token::CloseParenthesis))
+            }
+            Token::Number | Token::String | Token::Char | Token::Boolean | Token::None => {
+                PatternSyntax::Literal(parser.advance())
+            }
+            _ => PatternSyntax::Identifier(parser.expect(Token::Identifier), vec![]),
+        }
+    }
+
+    pub fn parse(parser: &mut Parser) -> PatternSyntax {
+        if parser.current() != Token::Identifier {
+            return PatternSyntax::primary(parser);
+        }
+
+        let name = parser.advance();
+        let mut arguments = vec![];
+
+        while parser.current().is_primary() {
+            arguments.push(PatternSyntax::primary(parser));
+        }
+
+        PatternSyntax::Identifier(name, arguments)
+    }
+
+    pub fn leaf(&self) -> Leaf {
+        match self {
+            PatternSyntax::Identifier(leaf, _)
+            | PatternSyntax::Literal(leaf)
+            | PatternSyntax::Group(leaf, _, _)
+            | PatternSyntax::Tuple(leaf, _, _) => *leaf,
+        }
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            PatternSyntax::Identifier(name, arguments) => match arguments.last() {
+                Some(argument) => name.1.range().start..argument.range().end,
+                None => name.1.range(),
+            },
+            PatternSyntax::Literal(literal) => literal.1.range(),
+            PatternSyntax::Group(open, _, close) => open.1.range().start..close.1.range().end,
+            PatternSyntax::Tuple(open, _, close) => open.1.range().start..close.1.range().end,
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex, received: Type) {
+        match self {
+            PatternSyntax::Identifier(name, _)
+                if PatternSyntax::is_wildcard(interpreter, *name) => {}
+            PatternSyntax::Identifier(name, arguments)
+                if PatternSyntax::is_constructor(interpreter, *name) =>
+            {
+                let constructor =
+                    match interpreter.lookup_constructor(scope, &interpreter.source(*name)) {
+                        Some(constructor) => constructor,
+                        None => {
+                            interpreter.error(Error::UnknownName(
+                                interpreter.range(*name),
+                                interpreter.source(*name),
+                            ));
+                            return;
+                        }
+                    };
+
+                if constructor.arity != arguments.len() {
+                    interpreter.error(Error::WrongArguments(
+                        self.range(),
+                        interpreter.source(*name),
+                        constructor.arity,
+                        arguments.len(),
+                    ));
+                    return;
+                }
+
+                let mut r#type = interpreter.instantiate(constructor.r#type);
+                let mut fields = vec![];
+
+                while let Type::Closure(field, r#return) = r#type {
+                    fields.push(*field);
+                    r#type = *r#return;
+                }
+
+                interpreter.expect(r#type, received, self.range());
+
+                for (argument, field) in arguments.iter().zip(fields) {
+                    argument.bind(interpreter, scope, field);
+                }
+            }
+            PatternSyntax::Identifier(name, arguments) => {
+                if !arguments.is_empty() {
+                    interpreter.error(Error::WrongArguments(
+                        self.range(),
+                        interpreter.source(*name),
+                        0,
+                        arguments.len(),
+                    ));
+                }
+
+                let source = interpreter.source(*name);
+                if interpreter.type_map(scope).contains_key(&source) {
+                    interpreter.error(Error::Reassignment(interpreter.range(*name), source));
+                    return;
+                }
+
+                interpreter.declare(scope, *name, received);
+            }
+            PatternSyntax::Literal(literal) => {
+                let r#type = LiteralSyntax(*literal).bind();
+                interpreter.expect(r#type, received, self.range());
+            }
+            PatternSyntax::Group(_, pattern, _) => pattern.bind(interpreter, scope, received),
+            PatternSyntax::Tuple(_, elements, _) => {
+                let types: Vec<Type> = elements.iter().map(|_| interpreter.fresh()).collect();
+                interpreter.expect(Type::Tuple(types.clone()), received, self.range());
+
+                for (element, r#type) in elements.iter().zip(types) {
+                    element.bind(interpreter, scope, r#type);
+                }
+            }
+        }
+    }
+
+    pub fn check_exhaustive(&self, interpreter: &mut Interpreter, scope: ScopeIndex) {
+        let rows = [vec![self.pattern(interpreter)]];
+
+        if let Some(witness) = interpreter.missing(scope, &rows, 1) {
+            interpreter.error(Error::NonExhaustiveMatch(
+                self.range(),
+                format!("{:?}", witness[0]),
+            ));
+        }
+    }
+
+    pub fn assign(
+        &self,
+        interpreter: &mut Interpreter,
+        scope: ScopeIndex,
+        value: Value,
+    ) -> Result<(), Error> {
+        let mut bindings = vec![];
+
+        if !self.matches(interpreter, &value, &mut bindings) {
+            return Err(Error::RuntimeError(
+                self.range(),
+                String::from("Pattern did not match the value"),
+            ));
+        }
+
+        for (name, value) in bindings {
+            interpreter.map(scope).insert(name, value);
+        }
+
+        Ok(())
+    }
+
+    pub fn matches(
+        &self,
+        interpreter: &mut Interpreter,
+        value: &Value,
+        bindings: &mut Vec<(String, Value)>,
+    ) -> bool {
+        match self {
+            PatternSyntax::Identifier(name, _)
+                if PatternSyntax::is_wildcard(interpreter, *name) =>
+            {
+                true
+            }
+            PatternSyntax::Identifier(name, arguments)
+                if PatternSyntax::is_constructor(interpreter, *name) =>
+            {
+                let (variant, fields) = value.clone().unwrap_variant();
+
+                variant == interpreter.source(*name)
+                    && arguments
+                        .iter()
+                        .zip(fields.iter())
+                        .all(|(argument, field)| argument.matches(interpreter, field, bindings))
+            }
+            PatternSyntax::Identifier(name, _) => {
+                bindings.push((interpreter.source(*name), value.clone()));
+                true
+            }
+            PatternSyntax::Literal(literal) => LiteralSyntax(*literal).eval(interpreter) == *value,
+            PatternSyntax::Group(_, pattern, _) => pattern.matches(interpreter, value, bindings),
+            PatternSyntax::Tuple(_, elements, _) => elements
+                .iter()
+                .zip(value.clone().unwrap_tuple().iter())
+                .all(|(element, value)| element.matches(interpreter, value, bindings)),
+        }
+    }
+
+    pub fn pattern(&self, interpreter: &Interpreter) -> Pattern {
+        match self {
+            PatternSyntax::Identifier(name, arguments)
+                if PatternSyntax::is_constructor(interpreter, *name) =>
+            {
+                Pattern::Constructor(
+                    interpreter.source(*name),
+                    arguments
+                        .iter()
+                        .map(|argument| argument.pattern(interpreter))
+                        .collect(),
+                )
+            }
+            PatternSyntax::Identifier(_, _) => Pattern::Any,
+            PatternSyntax::Literal(literal) => match literal.0 {
+                Token::Boolean | Token::None => {
+                    Pattern::Constructor(interpreter.source(*literal), vec![])
+                }
+                _ => Pattern::Literal(interpreter.source(*literal)),
+            },
+            PatternSyntax::Group(_, pattern, _) => pattern.pattern(interpreter),
+            PatternSyntax::Tuple(_, elements, _) => Pattern::Constructor(
+                ",".repeat(elements.len() - 1),
+                elements
+                    .iter()
+                    .map(|element| element.pattern(interpreter))
+                    .collect(),
+            ),
+        }
+    }
+}
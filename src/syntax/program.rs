@@ -0,0 +1,162 @@
+use crate::interpreter::{
+    r#type::Type,
+    scope::ScopeIndex,
+    value::{Evaluation, Value},
+    Interpreter,
+};
+
+use super::{Parser, Syntax, Token, TypeDefinitionSyntax};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ProgramSyntax(pub Vec<Syntax>);
+
+impl ProgramSyntax {
+    pub fn skip_separators(parser: &mut Parser) {
+        while parser.current().is_separator() {
+            parser.advance();
+        }
+    }
+}
+
+impl ProgramSyntax {
+    pub fn parse(parser: &mut Parser) -> ProgramSyntax {
+        let mut statements = vec![];
+
+        ProgramSyntax::skip_separators(parser);
+
+        while parser.current() != Token::EndOfFile {
+            statements.push(Syntax::parse(parser));
+
+            if parser.current() != Token::EndOfFile && !parser.current().is_separator() {
+                parser.expect(Token::Newline);
+            }
+
+            ProgramSyntax::skip_separators(parser);
+        }
+
+        ProgramSyntax(statements)
+    }
+
+    fn type_definitions(&self) -> Vec<&TypeDefinitionSyntax> {
+        self.0
+            .iter()
+            .filter_map(|statement| match statement {
+                Syntax::TypeDefinition(type_definition) => Some(type_definition),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let type_definitions = self.type_definitions();
+        let names: Vec<String> = type_definitions
+            .iter()
+            .map(|type_definition| interpreter.source(type_definition.name))
+            .collect();
+
+        let declared: Vec<bool> = type_definitions
+            .iter()
+            .map(|type_definition| type_definition.declare(interpreter, scope, &names))
+            .collect();
+
+        for (type_definition, declared) in type_definitions.iter().zip(declared) {
+            if declared {
+                type_definition.define(interpreter, scope, &names);
+            }
+        }
+
+        let mut signatures = vec![];
+
+        for statement in self.0.iter() {
+            let signature = match statement {
+                Syntax::Assignment(assignment) => assignment.signature(interpreter, scope),
+                _ => None,
+            };
+
+            if let (Syntax::Assignment(assignment), Some(signature)) = (statement, &signature) {
+                assignment.declare(interpreter, scope, signature.r#type.clone());
+                let name = interpreter.source(assignment.name);
+                let depth = interpreter.returns.len();
+                interpreter.undefined.push((scope, name, depth));
+            }
+
+            signatures.push(signature);
+        }
+
+        let mut r#type = Type::Unit;
+
+        for (statement, signature) in self.0.iter().zip(signatures) {
+            r#type = match (statement, signature) {
+                (Syntax::Assignment(assignment), Some(signature)) => {
+                    let r#type = assignment.bind_declared(interpreter, scope, signature);
+                    let name = interpreter.source(assignment.name);
+                    interpreter
+                        .undefined
+                        .retain(|(undefined, other, _)| *undefined != scope || *other != name);
+                    r#type
+                }
+                (Syntax::TypeDefinition(_), _) => Type::Unit,
+                _ => interpreter.bind(statement.clone(), scope),
+            };
+
+            interpreter.check_accesses();
+            interpreter.check_comparisons();
+        }
+
+        interpreter.resolve(r#type)
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let mut value = Value::None;
+        let type_definitions = self.type_definitions();
+        let names: Vec<String> = type_definitions
+            .iter()
+            .map(|type_definition| interpreter.source(type_definition.name))
+            .collect();
+
+        for type_definition in type_definitions {
+            type_definition.eval(interpreter, scope, &names);
+        }
+
+        for statement in self.0.iter() {
+            value = match statement {
+                Syntax::TypeDefinition(_) => Value::None,
+                _ => interpreter.eval(statement.clone(), scope)?,
+            };
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{number::Number, run, value::Value},
+        syntax::Token,
+    };
+
+    #[test]
+    fn evaluates_statements_in_order() {
+        assert_eq!(
+            run("double = x: Number >> mul x 2\ndouble 21").unwrap(),
+            Value::Number(Number::from(42))
+        );
+        assert_eq!(
+            run("a = 1; b = add a 2\n\n b").unwrap(),
+            Value::Number(Number::from(3))
+        );
+        assert_eq!(run("").unwrap(), Value::None);
+    }
+
+    #[test]
+    fn reports_statements_that_do_not_end() {
+        match run("a = 1 )").unwrap_err().as_slice() {
+            [Error::UnexpectedToken(range, Token::Newline, Token::CloseParenthesis), ..] => {
+                assert_eq!(range.clone(), 6..7)
+            }
+            errors => panic!("Expected an unexpected token, got {:?}", errors),
+        }
+    }
+}
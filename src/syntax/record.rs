@@ -0,0 +1,148 @@
+use crate::{
+    error::Error,
+    interpreter::{
+        r#type::Type,
+        scope::ScopeIndex,
+        value::{Evaluation, Value},
+        Interpreter,
+    },
+};
+
+use super::{Branch, Leaf, Parser, Syntax, Token};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecordSyntax {
+    pub open: Leaf,
+    pub fields: Vec<FieldSyntax>,
+    pub close: Leaf,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct FieldSyntax {
+    pub name: Leaf,
+    pub equals: Leaf,
+    pub expression: Branch,
+}
+
+impl RecordSyntax {
+    pub fn parse(parser: &mut Parser) -> RecordSyntax {
+        let open = parser.assert(Token::OpenBrace);
+        let mut fields = vec![];
+        let sequence = std::mem::replace(&mut parser.sequence, false);
+
+        parser.skip_newlines_before(Token::Identifier);
+        while parser.current() == Token::Identifier {
+            fields.push(FieldSyntax {
+                name: parser.advance(),
+                equals: parser.expect(Token::Equals),
+                expression: Box::new(Syntax::parse(parser)),
+            });
+
+            parser.skip_newlines_before(Token::Comma);
+            if parser.current() != Token::Comma {
+                break;
+            }
+
+            parser.advance();
+            parser.skip_newlines_before(Token::Identifier);
+        }
+
+        parser.skip_newlines_before(Token::CloseBrace);
+        parser.sequence = sequence;
+
+        RecordSyntax {
+            open,
+            fields,
+            close: parser.expect(Token::CloseBrace),
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let mut fields: Vec<(String, Type)> = vec![];
+
+        for field in self.fields.iter() {
+            let name = interpreter.source(field.name);
+            let r#type = interpreter.bind(*field.expression.clone(), scope);
+
+            if fields.iter().any(|(other, _)| other == &name) {
+                interpreter.error(Error::DuplicateField(interpreter.range(field.name), name));
+                continue;
+            }
+
+            fields.push((name, r#type));
+        }
+
+        fields.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+        Type::Record(fields)
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let mut fields: Vec<(String, Value)> = vec![];
+
+        for field in self.fields.iter() {
+            let name = interpreter.source(field.name);
+            let value = interpreter.eval(*field.expression.clone(), scope)?;
+
+            if !fields.iter().any(|(other, _)| other == &name) {
+                fields.push((name, value));
+            }
+        }
+
+        fields.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+        Ok(Value::Record(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, number::Number, r#type::Type, run, value::Value},
+    };
+
+    #[test]
+    fn sorts_fields_by_name() {
+        assert_eq!(
+            infer("{ name = \"a\", age = 3 }").unwrap(),
+            Type::Record(vec![
+                (String::from("age"), Type::Number),
+                (String::from("name"), Type::String),
+            ])
+        );
+        assert_eq!(
+            run("{ b = 1, a = \"x\" }").unwrap(),
+            Value::Record(vec![
+                (String::from("a"), Value::String(String::from("x"))),
+                (String::from("b"), Value::Number(Number::from(1))),
+            ])
+        );
+    }
+
+    #[test]
+    fn checks_records_against_named_record_types() {
+        let person =
+            "type Person = { name: String, age: Number }\nolder = p: Person >> inc p.age\n";
+
+        assert_eq!(
+            run(&format!("{}older {{ age = 1, name = \"b\" }}", person)).unwrap(),
+            Value::Number(Number::from(2))
+        );
+        assert!(matches!(
+            run(&format!("{}older {{ age = 1 }}", person))
+                .unwrap_err()
+                .as_slice(),
+            [Error::UnexpectedType(_, _, _)]
+        ));
+    }
+
+    #[test]
+    fn rejects_duplicate_fields() {
+        match run("{ a = 1, a = 2 }").unwrap_err().as_slice() {
+            [Error::DuplicateField(range, name)] => {
+                assert_eq!(range.clone(), 9..10);
+                assert_eq!(name, "a");
+            }
+            errors => panic!("Expected a duplicate field, got {:?}", errors),
+        }
+    }
+}
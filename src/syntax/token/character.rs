@@ -1,9 +1,31 @@
 pub const TERMINATOR: char = '\0';
 pub const NEWLINE: char = '\n';
 pub const DOUBLE_QUOTE: char = '"';
+pub const SINGLE_QUOTE: char = '\'';
 pub const OPEN_PARENTHESIS: char = '(';
 pub const CLOSE_PARENTHESIS: char = ')';
 pub const UNDERSCORE: char = '_';
 pub const GT: char = '>';
+pub const HYPHEN: char = '-';
 pub const EQUALS: char = '=';
 pub const COLON: char = ':';
+pub const SEMICOLON: char = ';';
+pub const BAR: char = '|';
+pub const OPEN_BRACE: char = '{';
+pub const CLOSE_BRACE: char = '}';
+pub const COMMA: char = ',';
+pub const DOT: char = '.';
+pub const QUESTION: char = '?';
+pub const OPEN_BRACKET: char = '[';
+pub const CLOSE_BRACKET: char = ']';
+pub const HASH: char = '#';
+pub const BACKSLASH: char = '\\';
+pub const RAW: char = 'r';
+pub const DOLLAR: char = '$';
+pub const NUMBER_PREFIXES: [char; 5] = [
+    TERMINATOR,
+    OPEN_PARENTHESIS,
+    OPEN_BRACKET,
+    OPEN_BRACE,
+    COMMA,
+];
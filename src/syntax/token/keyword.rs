@@ -1,4 +1,11 @@
 pub const TRUE: &str = "true";
 pub const FALSE: &str = "false";
 pub const NONE: &str = "none";
-pub const KEYWORDS: [&str; 3] = [TRUE, FALSE, NONE];
+pub const IF: &str = "if";
+pub const THEN: &str = "then";
+pub const ELSE: &str = "else";
+pub const TYPE: &str = "type";
+pub const MATCH: &str = "match";
+pub const LET: &str = "let";
+pub const IN: &str = "in";
+pub const KEYWORDS: [&str; 10] = [TRUE, FALSE, NONE, IF, THEN, ELSE, TYPE, MATCH, LET, IN];
@@ -1,9 +1,11 @@
+use std::ops::Range;
+
 use super::*;
 use crate::error::Error;
 
 #[derive(Debug)]
 pub struct Lexer {
-    pub source: String,
+    pub source: Vec<char>,
     pub position: usize,
     pub description: Vec<Description>,
     pub tokens: Vec<Token>,
@@ -15,7 +17,7 @@ pub struct Lexer {
 impl Lexer {
     pub fn new(source: &str) -> Lexer {
         Lexer {
-            source: String::from(source),
+            source: source.chars().collect(),
             position: 0,
             description: Vec::new(),
             tokens: Vec::new(),
@@ -32,8 +34,8 @@ impl Lexer {
         self.description.push(description);
     }
 
-    pub fn chars(&self) -> Vec<char> {
-        String::from(&self.source).chars().collect()
+    pub fn chars(&self) -> &[char] {
+        &self.source
     }
 
     pub fn push(&mut self, token: Token, length: usize) {
@@ -61,6 +63,13 @@ impl Lexer {
         }
     }
 
+    pub fn previous(&self) -> char {
+        match self.position {
+            0 => TERMINATOR,
+            position => self.chars()[position - 1],
+        }
+    }
+
     pub fn peek(&self, ahead: usize) -> char {
         match self.chars().get(self.position + ahead + self.reach) {
             Some(char) => *char,
@@ -83,7 +92,7 @@ impl Lexer {
         if self.flag == self.position {
             self.errors.push(Error::UnknownToken(
                 self.position..self.position + 1,
-                String::from(&self.source[self.position..self.position + 1]),
+                String::from(self.current()),
             ));
             Token::unknown(self);
         }
@@ -108,3 +117,9 @@ pub struct Description {
     pub position: usize,
     pub length: usize,
 }
+
+impl Description {
+    pub fn range(&self) -> Range<usize> {
+        self.position..self.position + self.length
+    }
+}
@@ -1,11 +1,13 @@
 mod character;
 mod keyword;
 mod lexer;
+mod operator;
 
-use crossterm::style::Stylize;
+use crate::error::Error;
 
 use self::character::*;
 use self::keyword::*;
+use self::operator::*;
 
 pub use self::lexer::*;
 
@@ -15,29 +17,84 @@ pub enum Token {
     Space,
     Number,
     String,
+    Char,
     Boolean,
     Lambda,
+    Arrow,
     Equals,
     Unknown,
     OpenParenthesis,
     CloseParenthesis,
     Colon,
+    Semicolon,
+    Newline,
     EndOfFile,
     None,
+    If,
+    Then,
+    Else,
+    Type,
+    Match,
+    Let,
+    In,
+    Bar,
+    OpenBrace,
+    CloseBrace,
+    Comma,
+    Dot,
+    Question,
+    OpenBracket,
+    CloseBracket,
+    Comment,
+    Plus,
+    Minus,
+    Asterisk,
+    Slash,
+    DoublePlus,
+    DoubleEquals,
+    LessThan,
+    GreaterThan,
+    LessThanEquals,
+    GreaterThanEquals,
+    DoubleAmpersand,
+    DoubleBar,
+    Pipeline,
+    Compose,
 }
 
 pub fn register_tokens(lexer: &mut Lexer) {
+    lexer.register(Token::string);
+    lexer.register(Token::char);
     lexer.register(Token::identifier);
     lexer.register(Token::space);
+    lexer.register(Token::newline);
+    lexer.register(Token::comment);
     lexer.register(Token::number);
-    lexer.register(Token::string);
+    lexer.register(Token::operator);
     lexer.register(Token::boolean);
     lexer.register(Token::lambda);
+    lexer.register(Token::arrow);
     lexer.register(Token::open_parenthesis);
     lexer.register(Token::close_parenthesis);
     lexer.register(Token::colon);
+    lexer.register(Token::semicolon);
     lexer.register(Token::equals);
     lexer.register(Token::none);
+    lexer.register(Token::r#if);
+    lexer.register(Token::then);
+    lexer.register(Token::r#else);
+    lexer.register(Token::r#type);
+    lexer.register(Token::r#match);
+    lexer.register(Token::r#let);
+    lexer.register(Token::r#in);
+    lexer.register(Token::bar);
+    lexer.register(Token::open_brace);
+    lexer.register(Token::close_brace);
+    lexer.register(Token::comma);
+    lexer.register(Token::dot);
+    lexer.register(Token::question);
+    lexer.register(Token::open_bracket);
+    lexer.register(Token::close_bracket);
 }
 
 pub fn tokenize(source: &str, start: usize) -> Lexer {
@@ -66,41 +123,279 @@ impl Token {
     }
 
     pub fn space(lexer: &mut Lexer) {
-        while lexer.current().is_whitespace() {
+        while lexer.current().is_whitespace() && lexer.current() != NEWLINE {
             lexer.step()
         }
         lexer.add(Token::Space);
     }
 
-    pub fn number(lexer: &mut Lexer) {
-        while lexer.current().is_numeric() {
+    pub fn newline(lexer: &mut Lexer) {
+        if lexer.current() == NEWLINE {
             lexer.step()
         }
-        lexer.add(Token::Number);
+
+        lexer.add(Token::Newline)
     }
 
-    pub fn string(lexer: &mut Lexer) {
-        if lexer.current() == DOUBLE_QUOTE {
+    pub fn comment(lexer: &mut Lexer) {
+        if lexer.current() != HASH {
+            return;
+        }
+
+        match lexer.peek(1) {
+            OPEN_BRACKET => Token::block_comment(lexer),
+            _ => {
+                while lexer.current() != NEWLINE && lexer.current() != TERMINATOR {
+                    lexer.step()
+                }
+            }
+        }
+
+        lexer.add(Token::Comment)
+    }
+
+    fn block_comment(lexer: &mut Lexer) {
+        let mut depth = 0;
+
+        loop {
+            match (lexer.current(), lexer.peek(1)) {
+                (HASH, OPEN_BRACKET) => {
+                    depth += 1;
+                    lexer.step();
+                    lexer.step();
+                }
+                (CLOSE_BRACKET, HASH) => {
+                    depth -= 1;
+                    lexer.step();
+                    lexer.step();
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                (TERMINATOR, _) => {
+                    lexer.errors.push(Error::UnterminatedComment(
+                        lexer.position..lexer.position + lexer.reach,
+                    ));
+                    return;
+                }
+                _ => lexer.step(),
+            }
+        }
+    }
+
+    pub fn number(lexer: &mut Lexer) {
+        let previous = lexer.previous();
+        if lexer.current() == HYPHEN
+            && lexer.peek(1).is_ascii_digit()
+            && (previous.is_whitespace() || NUMBER_PREFIXES.contains(&previous))
+        {
+            lexer.step()
+        }
+
+        while lexer.current().is_ascii_digit() {
+            lexer.step()
+        }
+
+        if lexer.reach > 0 && lexer.current() == DOT && lexer.peek(1).is_ascii_digit() {
             lexer.step();
-            while lexer.current() != DOUBLE_QUOTE
-                && lexer.current() != TERMINATOR
-                && lexer.current() != NEWLINE
-            {
+            while lexer.current().is_ascii_digit() {
                 lexer.step()
             }
         }
-        if lexer.current() == DOUBLE_QUOTE {
+
+        lexer.add(Token::Number);
+    }
+
+    pub fn string(lexer: &mut Lexer) {
+        let raw = lexer.current() == RAW && lexer.peek(1) == DOUBLE_QUOTE;
+        if raw {
             lexer.step()
         }
+
+        if lexer.current() != DOUBLE_QUOTE {
+            return;
+        }
+
+        let open = lexer.position + lexer.reach;
+        let triple = lexer.peek(1) == DOUBLE_QUOTE && lexer.peek(2) == DOUBLE_QUOTE;
+        let delimiter = match triple {
+            true => 3,
+            false => 1,
+        };
+
+        for _ in 0..delimiter {
+            lexer.step()
+        }
+
+        loop {
+            match lexer.current() {
+                TERMINATOR => {
+                    lexer.errors.push(Error::UnterminatedString(open..open + 1));
+                    break;
+                }
+                NEWLINE if !triple => {
+                    lexer.errors.push(Error::UnterminatedString(open..open + 1));
+                    break;
+                }
+                DOUBLE_QUOTE
+                    if !triple
+                        || (lexer.peek(1) == DOUBLE_QUOTE && lexer.peek(2) == DOUBLE_QUOTE) =>
+                {
+                    for _ in 0..delimiter {
+                        lexer.step()
+                    }
+                    break;
+                }
+                BACKSLASH if !raw => Token::escape(lexer),
+                DOLLAR if !raw && lexer.peek(1) == OPEN_BRACE => Token::interpolation(lexer),
+                _ => lexer.step(),
+            }
+        }
+
         lexer.add(Token::String);
     }
 
+    pub fn char(lexer: &mut Lexer) {
+        if lexer.current() != SINGLE_QUOTE {
+            return;
+        }
+
+        let open = lexer.position + lexer.reach;
+        lexer.step();
+
+        let valid = match lexer.current() {
+            SINGLE_QUOTE | NEWLINE | TERMINATOR => false,
+            BACKSLASH => {
+                Token::escape(lexer);
+                true
+            }
+            _ => {
+                lexer.step();
+                true
+            }
+        };
+
+        if !valid || lexer.current() != SINGLE_QUOTE {
+            while !matches!(lexer.current(), SINGLE_QUOTE | NEWLINE | TERMINATOR) {
+                lexer.step()
+            }
+            if lexer.current() == SINGLE_QUOTE {
+                lexer.step()
+            }
+
+            let end = lexer.position + lexer.reach;
+            lexer.errors.push(Error::InvalidCharacter(open..end));
+        } else {
+            lexer.step()
+        }
+
+        lexer.add(Token::Char);
+    }
+
+    fn interpolation(lexer: &mut Lexer) {
+        let open = lexer.position + lexer.reach;
+        lexer.step();
+        lexer.step();
+
+        let mut depth = 1;
+        loop {
+            match lexer.current() {
+                TERMINATOR => {
+                    lexer
+                        .errors
+                        .push(Error::UnterminatedInterpolation(open..open + 2));
+                    return;
+                }
+                OPEN_BRACE => depth += 1,
+                CLOSE_BRACE if depth == 1 => {
+                    lexer.step();
+                    return;
+                }
+                CLOSE_BRACE => depth -= 1,
+                DOUBLE_QUOTE => {
+                    Token::nested_string(lexer);
+                    continue;
+                }
+                _ => (),
+            }
+            lexer.step();
+        }
+    }
+
+    fn nested_string(lexer: &mut Lexer) {
+        lexer.step();
+
+        loop {
+            match lexer.current() {
+                TERMINATOR => return,
+                BACKSLASH => lexer.step(),
+                DOLLAR if lexer.peek(1) == OPEN_BRACE => {
+                    Token::interpolation(lexer);
+                    continue;
+                }
+                DOUBLE_QUOTE => {
+                    lexer.step();
+                    return;
+                }
+                _ => (),
+            }
+            lexer.step();
+        }
+    }
+
+    fn escape(lexer: &mut Lexer) {
+        let start = lexer.position + lexer.reach;
+        lexer.step();
+
+        let valid = match lexer.current() {
+            'n' | 't' | 'r' | '0' | DOUBLE_QUOTE | SINGLE_QUOTE | BACKSLASH | DOLLAR => {
+                lexer.step();
+                true
+            }
+            'u' if lexer.peek(1) == OPEN_BRACE => {
+                lexer.step();
+                lexer.step();
+
+                let mut digits = String::new();
+                while lexer.current().is_ascii_hexdigit() {
+                    digits.push(lexer.current());
+                    lexer.step()
+                }
+
+                let closed = lexer.current() == CLOSE_BRACE;
+                if closed {
+                    lexer.step()
+                }
+
+                closed
+                    && digits.len() <= 6
+                    && u32::from_str_radix(&digits, 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .is_some()
+            }
+            TERMINATOR | NEWLINE => false,
+            _ => {
+                lexer.step();
+                false
+            }
+        };
+
+        if !valid {
+            let end = lexer.position + lexer.reach;
+            lexer.errors.push(Error::InvalidEscape(
+                start..end,
+                lexer.chars()[start..end].iter().collect(),
+            ));
+        }
+    }
+
     pub fn boolean(lexer: &mut Lexer) {
         while lexer.current().is_alphabetic() {
             lexer.step()
         }
 
-        if &lexer.span() == &keyword::TRUE || &lexer.span() == &keyword::FALSE {
+        if lexer.span() == keyword::TRUE || lexer.span() == keyword::FALSE {
             lexer.add(Token::Boolean)
         }
     }
@@ -110,13 +405,73 @@ impl Token {
             lexer.step()
         }
 
-        if &lexer.span() == &keyword::NONE {
+        if lexer.span() == keyword::NONE {
             lexer.add(Token::None)
         }
     }
 
+    pub fn r#if(lexer: &mut Lexer) {
+        Token::keyword(lexer, keyword::IF, Token::If)
+    }
+
+    pub fn then(lexer: &mut Lexer) {
+        Token::keyword(lexer, keyword::THEN, Token::Then)
+    }
+
+    pub fn r#else(lexer: &mut Lexer) {
+        Token::keyword(lexer, keyword::ELSE, Token::Else)
+    }
+
+    pub fn r#type(lexer: &mut Lexer) {
+        Token::keyword(lexer, keyword::TYPE, Token::Type)
+    }
+
+    pub fn r#match(lexer: &mut Lexer) {
+        Token::keyword(lexer, keyword::MATCH, Token::Match)
+    }
+
+    pub fn r#let(lexer: &mut Lexer) {
+        Token::keyword(lexer, keyword::LET, Token::Let)
+    }
+
+    pub fn r#in(lexer: &mut Lexer) {
+        Token::keyword(lexer, keyword::IN, Token::In)
+    }
+
+    fn keyword(lexer: &mut Lexer, keyword: &str, token: Token) {
+        while lexer.current().is_alphabetic() {
+            lexer.step()
+        }
+
+        if lexer.span() == *keyword {
+            lexer.add(token)
+        }
+    }
+
+    pub fn operator(lexer: &mut Lexer) {
+        if (lexer.current() == GT && lexer.peek(1) == GT && lexer.peek(2) != GT)
+            || (lexer.current() == HYPHEN && lexer.peek(1) == GT)
+        {
+            return;
+        }
+
+        let operator = OPERATORS.iter().find(|(operator, _)| {
+            operator
+                .chars()
+                .enumerate()
+                .all(|(index, char)| lexer.peek(index) == char)
+        });
+
+        if let Some((operator, token)) = operator {
+            for _ in 0..operator.len() {
+                lexer.step()
+            }
+            lexer.add(*token)
+        }
+    }
+
     pub fn lambda(lexer: &mut Lexer) {
-        if lexer.current() == GT && lexer.peek(1) == GT {
+        if lexer.current() == GT && lexer.peek(1) == GT && lexer.peek(2) != GT {
             lexer.step();
             lexer.step();
         }
@@ -124,8 +479,17 @@ impl Token {
         lexer.add(Token::Lambda)
     }
 
+    pub fn arrow(lexer: &mut Lexer) {
+        if lexer.current() == HYPHEN && lexer.peek(1) == GT {
+            lexer.step();
+            lexer.step();
+        }
+
+        lexer.add(Token::Arrow)
+    }
+
     pub fn equals(lexer: &mut Lexer) {
-        if lexer.current() == EQUALS {
+        if lexer.current() == EQUALS && lexer.peek(1) != EQUALS {
             lexer.step()
         }
 
@@ -156,8 +520,258 @@ impl Token {
         lexer.add(Token::Colon)
     }
 
+    pub fn semicolon(lexer: &mut Lexer) {
+        if lexer.current() == SEMICOLON {
+            lexer.step()
+        }
+
+        lexer.add(Token::Semicolon)
+    }
+
+    pub fn bar(lexer: &mut Lexer) {
+        if lexer.current() == BAR && lexer.peek(1) != BAR && lexer.peek(1) != GT {
+            lexer.step()
+        }
+
+        lexer.add(Token::Bar)
+    }
+
+    pub fn open_brace(lexer: &mut Lexer) {
+        if lexer.current() == OPEN_BRACE {
+            lexer.step()
+        }
+
+        lexer.add(Token::OpenBrace)
+    }
+
+    pub fn close_brace(lexer: &mut Lexer) {
+        if lexer.current() == CLOSE_BRACE {
+            lexer.step()
+        }
+
+        lexer.add(Token::CloseBrace)
+    }
+
+    pub fn comma(lexer: &mut Lexer) {
+        if lexer.current() == COMMA {
+            lexer.step()
+        }
+
+        lexer.add(Token::Comma)
+    }
+
+    pub fn dot(lexer: &mut Lexer) {
+        if lexer.current() == DOT {
+            lexer.step()
+        }
+
+        lexer.add(Token::Dot)
+    }
+
+    pub fn question(lexer: &mut Lexer) {
+        if lexer.current() == QUESTION {
+            lexer.step()
+        }
+
+        lexer.add(Token::Question)
+    }
+
+    pub fn open_bracket(lexer: &mut Lexer) {
+        if lexer.current() == OPEN_BRACKET {
+            lexer.step()
+        }
+
+        lexer.add(Token::OpenBracket)
+    }
+
+    pub fn close_bracket(lexer: &mut Lexer) {
+        if lexer.current() == CLOSE_BRACKET {
+            lexer.step()
+        }
+
+        lexer.add(Token::CloseBracket)
+    }
+
     pub fn unknown(lexer: &mut Lexer) {
         lexer.step();
         lexer.add(Token::Unknown);
     }
 }
+
+impl Token {
+    pub fn is_separator(&self) -> bool {
+        *self == Token::Newline || *self == Token::Semicolon
+    }
+
+    pub fn is_trivia(&self) -> bool {
+        *self == Token::Space || *self == Token::Comment
+    }
+
+    pub fn precedence(&self) -> Option<usize> {
+        match self {
+            Token::Pipeline => Some(0),
+            Token::DoubleBar => Some(1),
+            Token::DoubleAmpersand => Some(2),
+            Token::DoubleEquals
+            | Token::LessThan
+            | Token::GreaterThan
+            | Token::LessThanEquals
+            | Token::GreaterThanEquals => Some(3),
+            Token::DoublePlus => Some(4),
+            Token::Plus | Token::Minus => Some(5),
+            Token::Asterisk | Token::Slash => Some(6),
+            Token::Compose => Some(7),
+            _ => None,
+        }
+    }
+
+    pub fn is_primary(&self) -> bool {
+        matches!(
+            self,
+            Token::Identifier
+                | Token::Number
+                | Token::String
+                | Token::Char
+                | Token::Boolean
+                | Token::None
+                | Token::OpenParenthesis
+                | Token::OpenBrace
+                | Token::OpenBracket
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use super::*;
+    use crate::interpreter::run;
+
+    fn tokens(source: &str) -> Vec<(Token, Range<usize>)> {
+        let lexer = tokenize(source, 0);
+        assert!(lexer.errors.is_empty(), "{:?}", lexer.errors);
+        lexer
+            .tokens
+            .into_iter()
+            .zip(lexer.description)
+            .filter(|(token, _)| *token != Token::Space)
+            .map(|(token, description)| (token, description.range()))
+            .collect()
+    }
+
+    #[test]
+    fn reads_line_comments() {
+        assert_eq!(
+            tokens("x # note\ny"),
+            vec![
+                (Token::Identifier, 0..1),
+                (Token::Comment, 2..8),
+                (Token::Newline, 8..9),
+                (Token::Identifier, 9..10),
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_nested_block_comments() {
+        assert_eq!(
+            tokens("#[ outer #[ inner ]# outer ]#1"),
+            vec![(Token::Comment, 0..29), (Token::Number, 29..30)]
+        );
+        assert_eq!(run("add #[ inline ]# 1 2").unwrap(), run("3").unwrap());
+        assert_eq!(run("add -1 #[c]# -2").unwrap(), run("-3").unwrap());
+        assert_eq!(
+            run("match 1\n# first\n| 1 >> \"one\"\n# other\n| _ >> \"other\"").unwrap(),
+            run("\"one\"").unwrap()
+        );
+    }
+
+    #[test]
+    fn reports_unterminated_block_comments() {
+        for (source, location) in [("1 #[ never closed", 2..17), ("#[ a #[ b ]# 1", 0..14)] {
+            match tokenize(source, 0).errors.as_slice() {
+                [Error::UnterminatedComment(range)] => assert_eq!(range.clone(), location),
+                errors => panic!("Expected an unterminated comment, got {:?}", errors),
+            }
+        }
+    }
+
+    #[test]
+    fn reads_plain_multiline_and_raw_strings() {
+        for source in [
+            "\"a\\\"b\"",
+            "\"\"\"line\n\"two\" end\"\"\"",
+            "r\"C:\\path\"",
+        ] {
+            assert_eq!(
+                tokens(source),
+                vec![(Token::String, 0..source.chars().count())]
+            );
+        }
+    }
+
+    #[test]
+    fn reports_invalid_strings() {
+        for (source, location) in [("x = \"ab\\qc\"", 7..9), ("\"\\u{110000}\"", 1..11)] {
+            match tokenize(source, 0).errors.as_slice() {
+                [Error::InvalidEscape(range, _)] => assert_eq!(range.clone(), location),
+                errors => panic!("Expected an invalid escape, got {:?}", errors),
+            }
+        }
+
+        for (source, location) in [("x = \"abc\ny = 1", 4..5), ("\"\"\"never", 0..1)] {
+            match tokenize(source, 0).errors.as_slice() {
+                [Error::UnterminatedString(range)] => assert_eq!(range.clone(), location),
+                errors => panic!("Expected an unterminated string, got {:?}", errors),
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_interpolations_inside_their_string() {
+        for source in [
+            "\"a ${b} c\"",
+            "\"${\"in${\"ner\"}\"}\"",
+            "\"${ {a = \"}\"}.a }\"",
+        ] {
+            assert_eq!(
+                tokens(source),
+                vec![(Token::String, 0..source.chars().count())]
+            );
+        }
+    }
+
+    #[test]
+    fn reports_unterminated_interpolations() {
+        match tokenize("\"a ${x\"", 0).errors.as_slice() {
+            [Error::UnterminatedInterpolation(range), ..] => assert_eq!(range.clone(), 3..5),
+            errors => panic!("Expected an unterminated interpolation, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn reads_character_literals() {
+        for source in ["'a'", "'\\n'", "'\\''", "'\\u{41}'", "'é'"] {
+            assert_eq!(
+                tokens(source),
+                vec![(Token::Char, 0..source.chars().count())]
+            );
+        }
+        assert_eq!(tokens("\"it's\""), vec![(Token::String, 0..6)]);
+    }
+
+    #[test]
+    fn reports_invalid_character_literals() {
+        for (source, location) in [("''", 0..2), ("x = 'ab'", 4..8), ("x = 'a", 4..6)] {
+            match tokenize(source, 0).errors.as_slice() {
+                [Error::InvalidCharacter(range)] => assert_eq!(range.clone(), location),
+                errors => panic!("Expected an invalid character, got {:?}", errors),
+            }
+        }
+        assert!(matches!(
+            tokenize("'\\q'", 0).errors.as_slice(),
+            [Error::InvalidEscape(_, _)]
+        ));
+    }
+}
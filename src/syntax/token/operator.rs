@@ -0,0 +1,18 @@
+use super::Token;
+
+pub const OPERATORS: [(&str, Token); 14] = [
+    (">>>", Token::Compose),
+    ("|>", Token::Pipeline),
+    ("++", Token::DoublePlus),
+    ("==", Token::DoubleEquals),
+    ("<=", Token::LessThanEquals),
+    (">=", Token::GreaterThanEquals),
+    ("&&", Token::DoubleAmpersand),
+    ("||", Token::DoubleBar),
+    ("+", Token::Plus),
+    ("-", Token::Minus),
+    ("*", Token::Asterisk),
+    ("/", Token::Slash),
+    ("<", Token::LessThan),
+    (">", Token::GreaterThan),
+];
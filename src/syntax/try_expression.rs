@@ -0,0 +1,146 @@
+use crate::{
+    error::Error,
+    interpreter::{
+        r#type::Type,
+        scope::ScopeIndex,
+        value::{Evaluation, Value},
+        Interpreter,
+    },
+};
+
+use super::{Branch, Leaf};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TrySyntax {
+    pub expression: Branch,
+    pub question: Leaf,
+}
+
+impl TrySyntax {
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        let expression = interpreter.bind(*self.expression.clone(), scope);
+
+        let r#return = match interpreter.returns.last() {
+            Some(r#return) => r#return.clone(),
+            None => {
+                interpreter.error(Error::PropagationOutsideClosure(
+                    interpreter.range(self.question),
+                ));
+                return Type::Error;
+            }
+        };
+
+        let is_result = |r#type: Type| matches!(r#type, Type::Data(name, _) if name == "Result");
+        let value = interpreter.fresh();
+
+        if is_result(interpreter.prune(expression.clone()))
+            || is_result(interpreter.prune(r#return.clone()))
+        {
+            let error = interpreter.fresh();
+            if interpreter.expect(
+                Type::result(error.clone(), value.clone()),
+                expression,
+                self.expression.range(),
+            ) {
+                let propagated = Type::result(error, interpreter.fresh());
+                interpreter.expect(r#return, propagated, interpreter.range(self.question));
+            }
+        } else if interpreter.expect(
+            Type::option(value.clone()),
+            expression,
+            self.expression.range(),
+        ) {
+            let propagated = Type::option(interpreter.fresh());
+            interpreter.expect(r#return, propagated, interpreter.range(self.question));
+        }
+
+        value
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        match interpreter.eval(*self.expression.clone(), scope)? {
+            Value::Variant(name, _, mut fields) if name == "Some" || name == "Ok" => {
+                Ok(fields.remove(0))
+            }
+            value => Err(Error::Propagation(value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, number::Number, r#type::Type, run, value::Value},
+    };
+
+    #[test]
+    fn unwraps_present_values() {
+        let sum = "sum = a, b >> Some ((parse_number a)? + (parse_number b)?)\n";
+
+        assert_eq!(
+            run(&format!("{}sum \"1\" \"2\"", sum)).unwrap(),
+            Value::some(Value::Number(Number::from(3)))
+        );
+        assert_eq!(
+            run(&format!("{}sum \"1\" \"x\"", sum)).unwrap(),
+            Value::none()
+        );
+    }
+
+    #[test]
+    fn applies_to_the_whole_call() {
+        let sum = "sum = a, b >> Some (parse_number a? + parse_number b?)\n";
+
+        assert_eq!(
+            run(&format!("{}sum \"1\" \"2\"", sum)).unwrap(),
+            Value::some(Value::Number(Number::from(3)))
+        );
+        assert_eq!(
+            run("f = n >> Ok (checked_div 10 n? + 1)\nf 0").unwrap(),
+            Value::err(Value::String(String::from("Division by zero")))
+        );
+    }
+
+    #[test]
+    fn returns_errors_from_the_enclosing_closure() {
+        let source = "half = n >> if n == 0 then Err \"zero\" else Ok (n / 2)\n\
+                      f = n >> Ok ((checked_div 10 n)? + (half n)?)\n";
+
+        assert_eq!(
+            run(&format!("{}f 5", source)).unwrap(),
+            Value::ok(Value::Number(Number::from(4)))
+        );
+        assert_eq!(
+            run(&format!("{}f 0", source)).unwrap(),
+            Value::err(Value::String(String::from("Division by zero")))
+        );
+    }
+
+    #[test]
+    fn requires_an_enclosing_closure() {
+        match infer("(parse_number \"1\")?").unwrap_err().as_slice() {
+            [Error::PropagationOutsideClosure(range)] => assert_eq!(range.clone(), 18..19),
+            errors => panic!("Expected a propagation error, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn requires_a_matching_return_type() {
+        for source in [
+            "f = n >> (parse_number n)? + 1",
+            "f = s >> Ok (parse_number s)?",
+            "f: String -> Option Number = s >> Some (checked_div 1 2)?",
+        ] {
+            assert!(matches!(
+                infer(source).unwrap_err().as_slice(),
+                [Error::UnexpectedType(_, _, _)]
+            ));
+        }
+
+        assert_eq!(
+            infer("x: Result String Number = Ok 1\nx").unwrap(),
+            Type::result(Type::String, Type::Number)
+        );
+    }
+}
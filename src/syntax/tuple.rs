@@ -0,0 +1,122 @@
+use crate::{
+    error::Error,
+    interpreter::{
+        r#type::Type,
+        scope::ScopeIndex,
+        value::{Evaluation, Value},
+        Interpreter,
+    },
+};
+
+use super::{Leaf, Syntax};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TupleSyntax {
+    pub open: Leaf,
+    pub elements: Vec<Syntax>,
+    pub close: Leaf,
+}
+
+impl TupleSyntax {
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        Type::Tuple(
+            self.elements
+                .iter()
+                .map(|element| interpreter.bind(element.clone(), scope))
+                .collect(),
+        )
+    }
+
+    pub fn eval(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Evaluation {
+        let elements = self
+            .elements
+            .iter()
+            .map(|element| interpreter.eval(element.clone(), scope))
+            .collect::<Result<Vec<Value>, Error>>()?;
+
+        Ok(Value::Tuple(elements))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, number::Number, r#type::Type, run, value::Value},
+    };
+
+    #[test]
+    fn builds_tuples_of_mixed_types() {
+        assert_eq!(
+            infer("(1, \"a\")").unwrap(),
+            Type::Tuple(vec![Type::Number, Type::String])
+        );
+        assert_eq!(
+            run("(1, true)").unwrap(),
+            Value::Tuple(vec![Value::Number(Number::from(1)), Value::Boolean(true)])
+        );
+        assert_eq!(run("(add 1 2)").unwrap(), Value::Number(Number::from(3)));
+    }
+
+    #[test]
+    fn destructures_tuples() {
+        assert_eq!(
+            run("(q, r) = divmod 7 2\nq * 10 + r").unwrap(),
+            Value::Number(Number::from(31))
+        );
+        assert_eq!(
+            run("((a, b), c) = ((1, 2), 3)\na + b + c").unwrap(),
+            Value::Number(Number::from(6))
+        );
+        assert_eq!(
+            run("swap = (a, b) >> (b, a)\nswap (1, \"x\")").unwrap(),
+            Value::Tuple(vec![
+                Value::String(String::from("x")),
+                Value::Number(Number::from(1))
+            ])
+        );
+        assert_eq!(
+            run("first = (a, _) >> a\nfirst (5, \"z\")").unwrap(),
+            Value::Number(Number::from(5))
+        );
+    }
+
+    #[test]
+    fn rejects_tuples_of_the_wrong_size() {
+        assert!(matches!(
+            run("(a, b) = (1, 2, 3)").unwrap_err().as_slice(),
+            [Error::UnexpectedType(_, _, _)]
+        ));
+        match run("match (1, true) | (n, true) >> n")
+            .unwrap_err()
+            .as_slice()
+        {
+            [Error::NonExhaustiveMatch(_, witness)] => assert_eq!(witness, "(_, false)"),
+            errors => panic!("Expected a non-exhaustive match, got {:?}", errors),
+        }
+    }
+
+    #[test]
+    fn reports_the_pattern_as_the_expected_type() {
+        match infer("(a, b) = 1").unwrap_err().as_slice() {
+            [Error::UnexpectedType(_, expected, received)] => {
+                assert_eq!(format!("{:?}", expected), "(a, b)");
+                assert_eq!(**received, Type::Number);
+            }
+            errors => panic!("Expected an unexpected type, got {:?}", errors),
+        }
+        match infer(
+            "f = x >> x + 1
+(a, b) = f",
+        )
+        .unwrap_err()
+        .as_slice()
+        {
+            [Error::UnexpectedType(_, expected, received)] => {
+                assert_eq!(format!("{:?}", expected), "(a, b)");
+                assert_eq!(format!("{:?}", received), "Number -> Number");
+            }
+            errors => panic!("Expected an unexpected type, got {:?}", errors),
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::{
+    error::Error,
+    interpreter::{
+        inference::substitute,
+        r#type::Type,
+        scope::{Constructor, Scope, ScopeIndex},
+        value::Value,
+        Interpreter,
+    },
+};
+
+use super::{Leaf, Parser, Token, TypeSyntax};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TypeDefinitionSyntax {
+    pub r#type: Leaf,
+    pub name: Leaf,
+    pub params: Vec<Leaf>,
+    pub equals: Leaf,
+    pub bar: Option<Leaf>,
+    pub variants: Vec<TypeSyntax>,
+}
+
+impl TypeDefinitionSyntax {
+    pub fn parse(parser: &mut Parser) -> TypeDefinitionSyntax {
+        let r#type = parser.assert(Token::Type);
+        let name = parser.expect(Token::Identifier);
+        let mut params = vec![];
+
+        while parser.current() == Token::Identifier {
+            params.push(parser.advance());
+        }
+
+        let equals = parser.expect(Token::Equals);
+
+        parser.skip_newlines_before(Token::Bar);
+        let bar = match parser.current() {
+            Token::Bar => Some(parser.advance()),
+            _ => None,
+        };
+
+        let mut variants = vec![TypeSyntax::parse(parser)];
+
+        parser.skip_newlines_before(Token::Bar);
+        while parser.current() == Token::Bar {
+            parser.advance();
+            variants.push(TypeSyntax::parse(parser));
+            parser.skip_newlines_before(Token::Bar);
+        }
+
+        TypeDefinitionSyntax {
+            r#type,
+            name,
+            params,
+            equals,
+            bar,
+            variants,
+        }
+    }
+
+    fn head(variant: &TypeSyntax) -> Option<(Leaf, Vec<TypeSyntax>)> {
+        match variant {
+            TypeSyntax::Name(name) => Some((*name, vec![])),
+            TypeSyntax::Application(name, fields) => Some((*name, fields.clone())),
+            _ => None,
+        }
+    }
+
+    pub fn is_data(
+        &self,
+        interpreter: &mut Interpreter,
+        scope: ScopeIndex,
+        names: &[String],
+    ) -> bool {
+        if self.variants.len() > 1 || self.bar.is_some() {
+            return true;
+        }
+
+        match TypeDefinitionSyntax::head(&self.variants[0]) {
+            Some((head, fields)) => {
+                let source = interpreter.source(head);
+                if source == interpreter.source(self.name) {
+                    return true;
+                }
+
+                let is_type = names.contains(&source)
+                    || interpreter.lookup_type_definition(scope, head).is_some();
+
+                !fields.is_empty() && !is_type && !TypeSyntax::is_variable(interpreter, head)
+            }
+            None => false,
+        }
+    }
+
+    pub fn declare(
+        &self,
+        interpreter: &mut Interpreter,
+        scope: ScopeIndex,
+        names: &[String],
+    ) -> bool {
+        if interpreter
+            .lookup_type_definition(scope, self.name)
+            .is_some()
+        {
+            interpreter.error(Error::TypeRedefinition(
+                interpreter.range(self.name),
+                interpreter.source(self.name),
+            ));
+            return false;
+        }
+
+        if self.is_data(interpreter, scope, names) {
+            let arguments = (0..self.params.len()).map(Type::Generic).collect();
+            let data = Type::Data(interpreter.source(self.name), arguments);
+            interpreter.define_type(scope, self.name, data);
+        }
+
+        true
+    }
+
+    pub fn define(&self, interpreter: &mut Interpreter, scope: ScopeIndex, names: &[String]) {
+        interpreter.chain.push(Scope::new(scope));
+        let definition_scope = interpreter.chain.len() - 1;
+        let mut generics = HashMap::new();
+
+        for (index, param) in self.params.iter().enumerate() {
+            let variable = interpreter.fresh();
+            if let Type::Variable(id) = variable {
+                generics.insert(id, Type::Generic(index));
+            }
+            interpreter.define_type(definition_scope, *param, variable);
+        }
+
+        let is_data = self.is_data(interpreter, scope, names);
+        let mut types = vec![];
+
+        for variant in self.variants.iter() {
+            match TypeDefinitionSyntax::head(variant) {
+                Some((_, fields)) if is_data => types.extend(fields),
+                _ => types.push(variant.clone()),
+            }
+        }
+
+        for r#type in types {
+            for name in r#type.names() {
+                if TypeSyntax::is_variable(interpreter, name)
+                    && interpreter
+                        .lookup_type_definition(definition_scope, name)
+                        .is_none()
+                {
+                    interpreter.error(Error::UnknownType(
+                        interpreter.range(name),
+                        interpreter.source(name),
+                    ));
+                }
+            }
+        }
+
+        if !is_data {
+            if interpreter
+                .lookup_type_definition(scope, self.name)
+                .is_some()
+            {
+                interpreter.error(Error::TypeRedefinition(
+                    interpreter.range(self.name),
+                    interpreter.source(self.name),
+                ));
+                return;
+            }
+
+            let definition = self.variants[0].bind(interpreter, definition_scope);
+            let definition = substitute(interpreter.resolve(definition), &generics);
+            interpreter.define_type(scope, self.name, definition);
+            return;
+        }
+
+        let arguments = (0..self.params.len()).map(Type::Generic).collect();
+        let data = Type::Data(interpreter.source(self.name), arguments);
+        let siblings: Vec<String> = self
+            .variants
+            .iter()
+            .filter_map(TypeDefinitionSyntax::head)
+            .map(|(head, _)| interpreter.source(head))
+            .collect();
+
+        for (head, fields) in self.variants.iter().filter_map(TypeDefinitionSyntax::head) {
+            if interpreter.lookup(scope, head).is_some() {
+                interpreter.error(Error::Reassignment(
+                    interpreter.range(head),
+                    interpreter.source(head),
+                ));
+                continue;
+            }
+
+            let mut r#type = data.clone();
+
+            for field in fields.iter().rev() {
+                let field = field.bind(interpreter, definition_scope);
+                let field = substitute(interpreter.resolve(field), &generics);
+                r#type = Type::Closure(Box::new(field), Box::new(r#type));
+            }
+
+            interpreter.declare(scope, head, r#type.clone());
+            interpreter.define_constructor(
+                scope,
+                head,
+                Constructor {
+                    data: interpreter.source(self.name),
+                    r#type,
+                    arity: fields.len(),
+                    siblings: siblings.clone(),
+                },
+            );
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        if self.declare(interpreter, scope, &[]) {
+            self.define(interpreter, scope, &[]);
+        }
+
+        Type::Unit
+    }
+
+    pub fn eval(
+        &self,
+        interpreter: &mut Interpreter,
+        scope: ScopeIndex,
+        names: &[String],
+    ) -> Value {
+        if !self.is_data(interpreter, scope, names) {
+            return Value::None;
+        }
+
+        let variants = self.variants.iter().filter_map(TypeDefinitionSyntax::head);
+
+        for (index, (head, fields)) in variants.enumerate() {
+            let name = interpreter.source(head);
+            let value = TypeDefinitionSyntax::construct(name.clone(), index, fields.len(), vec![]);
+            interpreter.map(scope).insert(name, value);
+        }
+
+        Value::None
+    }
+
+    pub fn construct(name: String, index: usize, arity: usize, fields: Vec<Value>) -> Value {
+        if fields.len() == arity {
+            return Value::Variant(name, index, fields);
+        }
+
+        Value::Closure(Arc::new(move |field, _| {
+            let mut fields = fields.clone();
+            fields.push(field);
+            Ok(TypeDefinitionSyntax::construct(
+                name.clone(),
+                index,
+                arity,
+                fields,
+            ))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{number::Number, run, value::Value},
+    };
+
+    #[test]
+    fn defines_single_nullary_constructors() {
+        assert_eq!(
+            run("type Color = | Red\nRed").unwrap(),
+            Value::Variant(String::from("Red"), 0, vec![])
+        );
+        assert_eq!(
+            run("type Token = Token\nmatch Token | Token >> 1").unwrap(),
+            Value::Number(Number::from(1))
+        );
+    }
+
+    #[test]
+    fn defines_aliases_for_known_types() {
+        assert_eq!(
+            run("type Age = Number\nage: Age = 3\nage").unwrap(),
+            Value::Number(Number::from(3))
+        );
+        assert_eq!(
+            run("type Id = Shape\ntype Shape = Circle Number\nid: Id = Circle 1\nid").unwrap(),
+            Value::Variant(
+                String::from("Circle"),
+                0,
+                vec![Value::Number(Number::from(1))]
+            )
+        );
+    }
+
+    #[test]
+    fn defines_data_types_with_fields() {
+        assert_eq!(
+            run("type Shape = Circle Number | Square Number\nmatch Circle 2 | Circle r >> r | Square s >> s").unwrap(),
+            Value::Number(Number::from(2))
+        );
+        assert!(matches!(
+            run("type Bad = x").unwrap_err().as_slice(),
+            [Error::UnknownType(_, _)]
+        ));
+    }
+
+    #[test]
+    fn rejects_aliases_to_unknown_types() {
+        assert!(matches!(
+            run("type Age = Strng").unwrap_err().as_slice(),
+            [Error::UnknownType(_, name)] if name == "Strng"
+        ));
+        assert!(matches!(
+            run("type Num = Nope\nNope").unwrap_err().first(),
+            Some(Error::UnknownType(_, name)) if name == "Nope"
+        ));
+    }
+
+    #[test]
+    fn resolves_aliases_wherever_they_are_defined() {
+        assert_eq!(
+            run("f = g: Op >> g 2\ntype Op = Number -> Number\nf inc").unwrap(),
+            Value::Number(Number::from(3))
+        );
+        assert_eq!(
+            run("type Op = Number -> Number\ntype Twice = Op -> Op\nt: Twice = f >> x >> f (f x)\nt inc 0")
+                .unwrap(),
+            Value::Number(Number::from(2))
+        );
+    }
+
+    #[test]
+    fn rejects_redefined_types() {
+        for source in ["type Id = Number\ntype Id = String", "type Number = String"] {
+            assert!(matches!(
+                run(source).unwrap_err().as_slice(),
+                [Error::TypeRedefinition(_, _)]
+            ));
+        }
+    }
+}
@@ -1,25 +1,335 @@
-use crate::interpreter::{r#type::Type, scope::ScopeIndex, Interpreter};
+use std::ops::Range;
 
-use super::{Node, Parser, Token};
+use crate::{
+    error::Error,
+    interpreter::{r#type::Type, scope::ScopeIndex, Interpreter},
+};
+
+use super::{Leaf, Node, Parser, Token};
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct TypeExpressionSyntax {
-    pub colon: Node,
-    pub name: Node,
+    pub delimiter: Node,
+    pub body: TypeSyntax,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum TypeSyntax {
+    Name(Leaf),
+    Application(Leaf, Vec<TypeSyntax>),
+    Closure(Box<TypeSyntax>, Leaf, Box<TypeSyntax>),
+    Group(Leaf, Box<TypeSyntax>, Leaf),
+    Record(Leaf, Vec<(Leaf, Leaf, TypeSyntax)>, Leaf),
+    Tuple(Leaf, Vec<TypeSyntax>, Leaf),
 }
 
 impl TypeExpressionSyntax {
     pub fn parse(parser: &mut Parser) -> TypeExpressionSyntax {
         TypeExpressionSyntax {
-            colon: parser.assert(Token::Colon),
-            name: parser.expect(Token::Identifier),
+            delimiter: parser.assert(Token::Colon),
+            body: TypeSyntax::parse(parser),
         }
     }
 
+    pub fn split(self) -> (TypeExpressionSyntax, Option<TypeExpressionSyntax>) {
+        match self.body {
+            TypeSyntax::Closure(param, arrow, r#return) => (
+                TypeExpressionSyntax {
+                    delimiter: self.delimiter,
+                    body: *param,
+                },
+                Some(TypeExpressionSyntax {
+                    delimiter: arrow,
+                    body: *r#return,
+                }),
+            ),
+            body => (
+                TypeExpressionSyntax {
+                    delimiter: self.delimiter,
+                    body,
+                },
+                None,
+            ),
+        }
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.delimiter.1.range().start..self.body.range().end
+    }
+
     pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
-        match interpreter.lookup_type_definition(scope, self.name) {
-            Some(definition) => definition,
-            None => Type::None,
+        self.body.bind(interpreter, scope)
+    }
+}
+
+impl TypeSyntax {
+    pub fn is_variable(interpreter: &Interpreter, name: Leaf) -> bool {
+        match interpreter.source(name).chars().next() {
+            Some(first) => first.is_lowercase(),
+            None => false,
+        }
+    }
+
+    fn record(parser: &mut Parser) -> TypeSyntax {
+        let open = parser.assert(Token::OpenBrace);
+        let mut fields = vec![];
+
+        parser.skip_newlines_before(Token::Identifier);
+        while parser.current() == Token::Identifier {
+            fields.push((
+                parser.advance(),
+                parser.expect(Token::Colon),
+                TypeSyntax::parse(parser),
+            ));
+
+            parser.skip_newlines_before(Token::Comma);
+            if parser.current() != Token::Comma {
+                break;
+            }
+
+            parser.advance();
+            parser.skip_newlines_before(Token::Identifier);
         }
+
+        parser.skip_newlines_before(Token::CloseBrace);
+
+        TypeSyntax::Record(open, fields, parser.expect(Token::CloseBrace))
+    }
+
+    fn primary(parser: &mut Parser) -> TypeSyntax {
+        if parser.current() == Token::OpenBrace {
+            return TypeSyntax::record(parser);
+        }
+
+        if parser.current() == Token::OpenParenthesis {
+            let open = parser.advance();
+            let body = TypeSyntax::parse(parser);
+
+            if parser.current() != Token::Comma {
+                return TypeSyntax::Group(
+                    open,
+                    Box::new(body),
+                    parser.expect(Token::CloseParenthesis),
+                );
+            }
+
+            let mut elements = vec![body];
+            while parser.current() == Token::Comma {
+                parser.advance();
+                elements.push(TypeSyntax::parse(parser));
+            }
+
+            return TypeSyntax::Tuple(open, elements, parser.expect(Token::CloseParenthesis));
+        }
+
+        TypeSyntax::Name(parser.expect(Token::Identifier))
+    }
+
+    fn application(parser: &mut Parser) -> TypeSyntax {
+        if parser.current() != Token::Identifier {
+            return TypeSyntax::primary(parser);
+        }
+
+        let name = parser.advance();
+        let mut arguments = vec![];
+
+        while let Token::Identifier | Token::OpenParenthesis | Token::OpenBrace = parser.current() {
+            arguments.push(TypeSyntax::primary(parser));
+        }
+
+        match arguments.len() {
+            0 => TypeSyntax::Name(name),
+            _ => TypeSyntax::Application(name, arguments),
+        }
+    }
+
+    pub fn parse(parser: &mut Parser) -> TypeSyntax {
+        let param = TypeSyntax::application(parser);
+
+        if parser.current() == Token::Arrow {
+            return TypeSyntax::Closure(
+                Box::new(param),
+                parser.advance(),
+                Box::new(TypeSyntax::parse(parser)),
+            );
+        }
+
+        param
+    }
+
+    pub fn names(&self) -> Vec<Leaf> {
+        match self {
+            TypeSyntax::Name(name) => vec![*name],
+            TypeSyntax::Application(name, arguments) => {
+                let mut names = vec![*name];
+                for argument in arguments {
+                    names.extend(argument.names());
+                }
+                names
+            }
+            TypeSyntax::Closure(param, _, r#return) => {
+                let mut names = param.names();
+                names.extend(r#return.names());
+                names
+            }
+            TypeSyntax::Group(_, body, _) => body.names(),
+            TypeSyntax::Tuple(_, elements, _) => elements
+                .iter()
+                .flat_map(|element| element.names())
+                .collect(),
+            TypeSyntax::Record(_, fields, _) => fields
+                .iter()
+                .flat_map(|(_, _, r#type)| r#type.names())
+                .collect(),
+        }
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        match self {
+            TypeSyntax::Name(name) => name.1.range(),
+            TypeSyntax::Application(name, arguments) => match arguments.last() {
+                Some(argument) => name.1.range().start..argument.range().end,
+                None => name.1.range(),
+            },
+            TypeSyntax::Closure(param, _, r#return) => param.range().start..r#return.range().end,
+            TypeSyntax::Group(open, _, close) => open.1.range().start..close.1.range().end,
+            TypeSyntax::Record(open, _, close) => open.1.range().start..close.1.range().end,
+            TypeSyntax::Tuple(open, _, close) => open.1.range().start..close.1.range().end,
+        }
+    }
+
+    fn bind_name(
+        interpreter: &mut Interpreter,
+        scope: ScopeIndex,
+        name: Leaf,
+        arguments: Vec<Type>,
+    ) -> Type {
+        match interpreter.lookup_type_definition(scope, name) {
+            Some(definition) if definition.arity() != arguments.len() => {
+                interpreter.error(Error::WrongArguments(
+                    interpreter.range(name),
+                    interpreter.source(name),
+                    definition.arity(),
+                    arguments.len(),
+                ));
+                Type::Error
+            }
+            Some(definition) => definition.apply(&arguments),
+            None if TypeSyntax::is_variable(interpreter, name) && arguments.is_empty() => {
+                let variable = interpreter.fresh();
+                interpreter.define_type(scope, name, variable.clone());
+                variable
+            }
+            None => {
+                interpreter.error(Error::UnknownType(
+                    interpreter.range(name),
+                    interpreter.source(name),
+                ));
+                Type::Error
+            }
+        }
+    }
+
+    pub fn bind(&self, interpreter: &mut Interpreter, scope: ScopeIndex) -> Type {
+        match self {
+            TypeSyntax::Name(name) => TypeSyntax::bind_name(interpreter, scope, *name, vec![]),
+            TypeSyntax::Application(name, arguments) => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.bind(interpreter, scope))
+                    .collect();
+                TypeSyntax::bind_name(interpreter, scope, *name, arguments)
+            }
+            TypeSyntax::Closure(param, _, r#return) => Type::Closure(
+                Box::new(param.bind(interpreter, scope)),
+                Box::new(r#return.bind(interpreter, scope)),
+            ),
+            TypeSyntax::Group(_, body, _) => body.bind(interpreter, scope),
+            TypeSyntax::Tuple(_, elements, _) => Type::Tuple(
+                elements
+                    .iter()
+                    .map(|element| element.bind(interpreter, scope))
+                    .collect(),
+            ),
+            TypeSyntax::Record(_, fields, _) => {
+                let mut types: Vec<(String, Type)> = vec![];
+
+                for (name, _, r#type) in fields {
+                    let source = interpreter.source(*name);
+                    let r#type = r#type.bind(interpreter, scope);
+
+                    if types.iter().any(|(other, _)| other == &source) {
+                        interpreter.error(Error::DuplicateField(interpreter.range(*name), source));
+                        continue;
+                    }
+
+                    types.push((source, r#type));
+                }
+
+                types.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+                Type::Record(types)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::Error,
+        interpreter::{infer, number::Number, r#type::Type, run, value::Value},
+    };
+
+    fn closure(param: Type, r#return: Type) -> Type {
+        Type::Closure(Box::new(param), Box::new(r#return))
+    }
+
+    #[test]
+    fn accepts_function_types_in_annotations() {
+        assert_eq!(
+            run("apply = f: (Number -> Number) >> x: Number >> f x\napply inc 41").unwrap(),
+            Value::Number(Number::from(42))
+        );
+        assert_eq!(
+            run("twice = f: (Number -> Number) -> Number -> Number >> x: Number >> f (f x)\ntwice inc 1")
+                .unwrap(),
+            Value::Number(Number::from(3))
+        );
+        assert_eq!(
+            run("g: Number -> Number -> Number = add\ng 1 2").unwrap(),
+            Value::Number(Number::from(3))
+        );
+    }
+
+    #[test]
+    fn associates_arrows_to_the_right() {
+        assert_eq!(
+            infer("h: (Number -> Number) -> Number = f: (Number -> Number) >> f 1\nh").unwrap(),
+            closure(closure(Type::Number, Type::Number), Type::Number)
+        );
+        assert_eq!(
+            infer("g: Number -> Number -> Boolean = a >> b >> a == b\ng").unwrap(),
+            closure(Type::Number, closure(Type::Number, Type::Boolean))
+        );
+    }
+
+    #[test]
+    fn rejects_values_that_do_not_match_the_annotation() {
+        match run("g: Number -> String = inc").unwrap_err().as_slice() {
+            [Error::MismatchedTypeAssignment(range, name, received, expected)] => {
+                assert_eq!(range.clone(), 1..19);
+                assert_eq!(name, "g");
+                assert_eq!(**received, closure(Type::Number, Type::Number));
+                assert_eq!(**expected, closure(Type::Number, Type::String));
+            }
+            errors => panic!("Expected a mismatched assignment, got {:?}", errors),
+        }
+
+        assert!(matches!(
+            run("apply = f: (Number -> Number) >> f 1\napply 3")
+                .unwrap_err()
+                .as_slice(),
+            [Error::UnexpectedType(_, _, _)]
+        ));
     }
 }